@@ -0,0 +1,147 @@
+//! Compile-time checked [`utc::Utc`] literals.
+//!
+//! The macros expand to a call to [`utc::Utc::from_ymdhms_const`] and
+//! [`utc::Utc::with_nanosecond`], so the crate using them must also depend
+//! on `utc`.
+
+use proc_macro::{ Span, TokenStream, TokenTree, };
+
+/// Gets a [`utc::Utc`] from an RFC 3339 date/time literal, checked at
+/// compile time.
+///
+/// The literal may have a fraction of a second and a `Z` or `±HH:MM`
+/// offset, which is applied. A space may be used in place of the `T`.
+///
+/// # Examples
+///
+/// ```
+/// use utc_macros::utc;
+///
+/// const RELEASE: utc::Utc = utc!("2024-01-09T16:21:00.5Z");
+/// assert_eq!("2024-01-09T16:21:00.500000", RELEASE.as_rfc3339_nano());
+///
+/// let dt = utc!("2024-01-09T18:21:00+02:00");
+/// assert_eq!("2024-01-09T16:21:00", dt.as_rfc3339());
+///
+/// // Exactly the date/time parsed at run time.
+/// let hints = utc::ParseHints::new();
+/// let parsed = utc::Utc::parse_any("2024-01-09T16:21:59.1Z", &hints);
+/// assert_eq!(Ok(utc!("2024-01-09T16:21:59.1Z")), parsed);
+/// ```
+///
+/// Malformed or impossible date/times fail to compile:
+///
+/// ```compile_fail
+/// let dt = utc_macros::utc!("2023-02-29T00:00:00Z");
+/// ```
+///
+/// ```compile_fail
+/// let dt = utc_macros::utc!("tomorrow");
+/// ```
+#[proc_macro]
+pub fn utc(input: TokenStream) -> TokenStream {
+    let (literal, span) = match string_literal(input) {
+        Ok(literal) => literal,
+        Err(error) => return error,
+    };
+
+    let hints = utc::ParseHints::new()
+        .formats(&[utc::Format::Rfc3339, utc::Format::Iso8601]);
+    match utc::Utc::parse_any(&literal, &hints) {
+        Ok(dt) => expand(&dt),
+        Err(utc::ParseAnyError::Invalid(_)) => compile_error(
+            span, &format!("\"{literal}\" is not a valid date/time"),
+        ),
+        Err(_) => compile_error(
+            span, &format!("\"{literal}\" is not an RFC 3339 date/time"),
+        ),
+    }
+}
+
+/// Gets a [`utc::Utc`] at the start of the day from a `yyyy-MM-dd` date
+/// literal, checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use utc_macros::date;
+///
+/// const LAUNCH: utc::Utc = date!("2024-02-29");
+/// assert_eq!("2024-02-29T00:00:00", LAUNCH.as_rfc3339());
+/// ```
+///
+/// Malformed or impossible dates fail to compile:
+///
+/// ```compile_fail
+/// let dt = utc_macros::date!("2024-13-01");
+/// ```
+///
+/// ```compile_fail
+/// let dt = utc_macros::date!("2024-01-09T16:21:00Z");
+/// ```
+#[proc_macro]
+pub fn date(input: TokenStream) -> TokenStream {
+    let (literal, span) = match string_literal(input) {
+        Ok(literal) => literal,
+        Err(error) => return error,
+    };
+
+    let hints = utc::ParseHints::new().formats(&[utc::Format::Iso8601]);
+    match utc::Utc::parse_any(&literal, &hints) {
+        Ok(dt) if literal.len() == "yyyy-MM-dd".len() => expand(&dt),
+        Err(utc::ParseAnyError::Invalid(_)) => compile_error(
+            span, &format!("\"{literal}\" is not a valid date"),
+        ),
+        _ => compile_error(
+            span, &format!("\"{literal}\" is not a yyyy-MM-dd date"),
+        ),
+    }
+}
+
+/// Gets the contents and span of a single plain string literal.
+fn string_literal(input: TokenStream) -> Result<(String, Span), TokenStream> {
+    let mut tokens = input.into_iter();
+    let (token, rest) = (tokens.next(), tokens.next());
+    let literal = match (token, rest) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        (Some(token), _) => return Err(compile_error(
+            token.span(), "expected a single string literal",
+        )),
+        (None, _) => return Err(compile_error(
+            Span::call_site(), "expected a string literal",
+        )),
+    };
+
+    let text = literal.to_string();
+    match text.strip_prefix('"').and_then(|t| t.strip_suffix('"')) {
+        Some(contents) if !contents.contains('\\') => Ok((contents.to_string(), literal.span())),
+        _ => Err(compile_error(literal.span(), "expected a plain string literal")),
+    }
+}
+
+/// Expands to the date/time, with the whole seconds and nanoseconds given
+/// separately so that the fraction is exactly the parsed one.
+fn expand(dt: &utc::Utc) -> TokenStream {
+    format!(
+        "match ::utc::Utc::from_ymdhms_const(
+            {}usize, {}usize, {}usize, {}usize, {}usize, {}f64,
+        ).with_nanosecond({}u32) {{
+            ::core::option::Option::Some(dt) => dt,
+            ::core::option::Option::None => ::core::panic!(\"Nanosecond is out of range\"),
+        }}",
+        dt.year(), dt.month(), dt.day(), dt.hour(), dt.minute(), dt.second(), dt.nanosecond(),
+    ).parse().unwrap()
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let tokens: TokenStream = format!("::core::compile_error!({message:?})")
+        .parse()
+        .unwrap();
+
+    tokens.into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
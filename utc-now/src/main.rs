@@ -1,5 +1,119 @@
 use utc::Utc;
 
+#[cfg(test)]
+mod test;
+
+const USAGE: &str = "\
+Usage: utc-now [OPTION]
+
+Prints the current UTC date/time.
+
+Options:
+  -n, --normalize  Copy standard input to standard output, rewriting every
+                   timestamp found in RFC 3339 form
+  -t, --tai64n     Copy standard input to standard output, replacing the
+                   TAI64N label starting each line with its UTC date/time,
+                   like tai64nlocal
+  -o, --offset SERVER
+                   Print how far the local clock is behind the SNTP server,
+                   given as host or host:port, in seconds
+  -h, --help       Print this help
+";
+
+fn normalize() -> std::io::Result<()> {
+    use std::io::{ BufRead, Write, };
+
+    let hints = utc::ParseHints::new();
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    for line in std::io::stdin().lock().lines() {
+        writeln!(out, "{}", utc::normalize_timestamps(&line?, &hints))?;
+    }
+
+    Ok(())
+}
+
+/// Gets the leap second table of daemontools, which labels the system clock
+/// as TAI + 10 s whatever the number of leap seconds.
+fn daemontools_leap_seconds() -> utc::LeapSeconds {
+    utc::LeapSeconds::parse("2272060800 10").expect("Table must be valid")
+}
+
+/// Replaces the TAI64N label starting a line with its date/time.
+fn tai64n_line(line: &str, leap_seconds: &utc::LeapSeconds) -> String {
+    let label = line.get(..25).and_then(|label| label.parse::<utc::Tai64N>().ok());
+    match label.and_then(|label| label.to_utc_with(leap_seconds)) {
+        Some(dt) => format!("{dt:#.9}{}", &line[25..]),
+        None => line.to_string(),
+    }
+}
+
+fn tai64n() -> std::io::Result<()> {
+    use std::io::{ BufRead, Write, };
+
+    let leap_seconds = daemontools_leap_seconds();
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    for line in std::io::stdin().lock().lines() {
+        writeln!(out, "{}", tai64n_line(&line?, &leap_seconds))?;
+    }
+
+    Ok(())
+}
+
+/// Adds the NTP port to a server without one: a host, an IPv4 address or
+/// an IPv6 address, bracketed or not.
+fn with_ntp_port(server: &str) -> String {
+    if server.parse::<std::net::Ipv6Addr>().is_ok() {
+        format!("[{server}]:123")
+    } else if !server.contains(':') || server.ends_with(']') {
+        format!("{server}:123")
+    } else {
+        server.to_string()
+    }
+}
+
+fn offset(server: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let Some(server) = server else {
+        eprint!("utc-now: --offset needs a server\n\n{USAGE}");
+        std::process::exit(2);
+    };
+
+    let response = utc::SntpClient::new(with_ntp_port(&server)).query()?;
+    let offset = response.offset_nanos();
+    println!(
+        "{}{}.{:09} s (delay {:.9} s, stratum {})",
+        if offset < 0 { '-' } else { '+' },
+        offset.unsigned_abs() / 1_000_000_000,
+        offset.unsigned_abs() % 1_000_000_000,
+        response.delay().as_secs_f64(),
+        response.stratum(),
+    );
+    Ok(())
+}
+
 fn main() {
-    println!("{}", Utc::now().as_rfc3339_with_nano(6));
+    let mut args = std::env::args().skip(1);
+    let result: Result<(), Box<dyn std::error::Error>> = match args.next().as_deref() {
+        None => {
+            println!("{}", Utc::now().as_rfc3339_with_nano(6));
+            Ok(())
+        },
+        Some("-n" | "--normalize") => normalize().map_err(Into::into),
+        Some("-t" | "--tai64n") => tai64n().map_err(Into::into),
+        Some("-o" | "--offset") => offset(args.next()),
+        Some("-h" | "--help") => {
+            print!("{USAGE}");
+            Ok(())
+        },
+        Some(arg) => {
+            eprint!("utc-now: unknown option {arg}\n\n{USAGE}");
+            std::process::exit(2);
+        },
+    };
+
+    if let Err(e) = result {
+        eprintln!("utc-now: {e}");
+        std::process::exit(1);
+    }
 }
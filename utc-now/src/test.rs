@@ -0,0 +1,20 @@
+#[test]
+fn tai64n_like_tai64nlocal() {
+    // Written by multilog at 2024-01-09T16:21:00.002 system time.
+    let leap_seconds = crate::daemontools_leap_seconds();
+    assert_eq!(
+        "2024-01-09 16:21:00.002000000 hello",
+        crate::tai64n_line("@40000000659d7276001e8480 hello", &leap_seconds)
+    );
+    assert_eq!("not a label", crate::tai64n_line("not a label", &leap_seconds));
+}
+
+#[test]
+fn ntp_port_added_only_when_missing() {
+    assert_eq!("pool.ntp.org:123", crate::with_ntp_port("pool.ntp.org"));
+    assert_eq!("127.0.0.1:123", crate::with_ntp_port("127.0.0.1"));
+    assert_eq!("[::1]:123", crate::with_ntp_port("::1"));
+    assert_eq!("[::1]:123", crate::with_ntp_port("[::1]"));
+    assert_eq!("[::1]:1123", crate::with_ntp_port("[::1]:1123"));
+    assert_eq!("time.example:1123", crate::with_ntp_port("time.example:1123"));
+}
@@ -0,0 +1,50 @@
+use {
+    std::fmt::Write,
+    utc::Utc,
+    criterion:: { black_box, criterion_group, criterion_main, Criterion, },
+};
+
+/// Formats the way the `as_*` methods did before using `FormatBuf`.
+fn with_format_macro(dt: &Utc) -> String {
+    let nano = &format!("{:.6}", dt.nano())[2..];
+    format!(
+        "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}.{nano}",
+        dt.year(), dt.month(), dt.day(), dt.hour(), dt.minute(), dt.second()
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.123456_f64);
+
+    c.bench_function("rfc3339_nano_format_macro", |b| b.iter(|| {
+        with_format_macro(black_box(&dt))
+    }));
+    c.bench_function("as_rfc3339_nano", |b| b.iter(|| {
+        black_box(&dt).as_rfc3339_nano()
+    }));
+    c.bench_function("write_rfc3339_with_nano", |b| {
+        let mut s = String::with_capacity(64);
+        b.iter(|| {
+            s.clear();
+            black_box(&dt).write_rfc3339_with_nano(&mut s, 6).unwrap();
+            s.len()
+        })
+    });
+    c.bench_function("to_rfc3339_with_nano_buf", |b| b.iter(|| {
+        black_box(&dt).to_rfc3339_with_nano_buf(6)
+    }));
+    c.bench_function("to_rfc7231_buf", |b| b.iter(|| {
+        black_box(&dt).to_rfc7231_buf()
+    }));
+    c.bench_function("rfc7231_into_string", |b| {
+        let mut s = String::with_capacity(64);
+        b.iter(|| {
+            s.clear();
+            write!(s, "{}", black_box(&dt).to_rfc7231_buf()).unwrap();
+            s.len()
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -1,10 +1,27 @@
 use {
-    utc::Utc,
+    std::time::Duration,
+    utc::{ CachedClock, Clock, Utc, },
     criterion:: { criterion_group, criterion_main, Criterion, },
 };
 
 fn criterion_benchmark(c: &mut Criterion) {
+    #[allow(clippy::redundant_closure)]
     c.bench_function("now", |b| b.iter(|| Utc::now()));
+
+    let lazy = CachedClock::new();
+    c.bench_function("cached_now_lazy_1ms", |b| b.iter(|| lazy.now()));
+
+    let lazy = CachedClock::new().granularity(Duration::from_secs(1));
+    c.bench_function("cached_now_lazy_1s", |b| b.iter(|| lazy.now()));
+
+    let background = CachedClock::new().background();
+    c.bench_function("cached_now_background_1ms", |b| b.iter(|| background.now()));
+
+    #[cfg(feature = "coarse")]
+    {
+        let coarse = CachedClock::new().coarse(true);
+        c.bench_function("cached_now_lazy_coarse_1ms", |b| b.iter(|| coarse.now()));
+    }
 }
 
 criterion_group!(benches, criterion_benchmark);
@@ -0,0 +1,241 @@
+const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
+
+/// A reading of the wall clock truncated to the granularity.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Cached {
+    tick: i128,
+    utc: crate::Utc,
+}
+
+impl Cached {
+    pub(crate) fn new(nanos: i128, granularity: i128) -> Self {
+        let tick = nanos.div_euclid(granularity);
+        let utc = crate::Utc::from_nanos_since_epoch(tick * granularity)
+            .expect("Date must be >= 1970");
+        Self { tick, utc, }
+    }
+
+    /// Gets the reading for a later time, reusing the date when it has not
+    /// changed.
+    pub(crate) fn refresh(&self, nanos: i128, granularity: i128) -> Self {
+        let tick = nanos.div_euclid(granularity);
+        if tick == self.tick {
+            return *self;
+        }
+
+        let truncated = tick * granularity;
+        let day_start = self.tick * granularity - self.tick * granularity % NANOS_PER_DAY;
+        if truncated < day_start || truncated >= day_start + NANOS_PER_DAY {
+            return Self::new(nanos, granularity);
+        }
+
+        let of_day = truncated - day_start;
+        let utc = self.utc.with_seconds_of_day(
+            (of_day / 1_000_000_000) as usize,
+            (of_day % 1_000_000_000) as f64 / 1_000_000_000_f64,
+        );
+        Self { tick, utc, }
+    }
+
+    pub(crate) fn utc(&self) -> crate::Utc {
+        self.utc
+    }
+}
+
+/// A clock which keeps reusing one reading of the wall clock until the
+/// granularity has passed, for when reading the time is on a hot path.
+///
+/// Readings are truncated to the granularity, one millisecond by default.
+/// By default the wall clock is read lazily on every call and the date/time
+/// only worked out again once it moves into a new granule, reusing the date
+/// unless it has changed. With [`CachedClock::background`] a thread keeps
+/// the reading fresh instead, so calls never read the wall clock.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::{ CachedClock, Clock, Utc, };
+///
+/// let clock = CachedClock::new().granularity(Duration::from_secs(1));
+/// let now = clock.now();
+/// assert_eq!(0_f64, now.nano());
+/// assert!(now <= Utc::now());
+/// ```
+#[derive(Debug)]
+pub struct CachedClock {
+    granularity: core::time::Duration,
+    coarse: bool,
+    cached: std::sync::Arc<std::sync::RwLock<Cached>>,
+    background: Option<Background>,
+}
+
+impl CachedClock {
+    /// Gets a lazily refreshed clock with a granularity of one millisecond.
+    pub fn new() -> Self {
+        let granularity = core::time::Duration::from_millis(1);
+        let cached = Cached::new(wall_nanos(false), granularity.as_nanos() as i128);
+        Self {
+            granularity,
+            coarse: false,
+            cached: std::sync::Arc::new(std::sync::RwLock::new(cached)),
+            background: None,
+        }
+    }
+
+    /// Sets how long a reading is reused for, at least one nanosecond. Must
+    /// be set before [`CachedClock::background`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ CachedClock, Clock, };
+    ///
+    /// let clock = CachedClock::new().granularity(Duration::from_secs(60));
+    /// assert_eq!(0, clock.now().second());
+    /// ```
+    pub fn granularity(mut self, granularity: core::time::Duration) -> Self {
+        self.granularity = granularity.max(core::time::Duration::from_nanos(1));
+        self.store(Cached::new(self.wall_nanos(), self.granularity_nanos()));
+        self
+    }
+
+    /// Sets whether the wall clock is read with `CLOCK_REALTIME_COARSE`,
+    /// which is cheaper but only as precise as the kernel tick, typically a
+    /// few milliseconds. Has no effect outside of Linux. Must be set before
+    /// [`CachedClock::background`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ CachedClock, Clock, };
+    ///
+    /// let clock = CachedClock::new()
+    ///     .granularity(Duration::from_millis(10))
+    ///     .coarse(true);
+    /// println!("{}", clock.now());
+    /// ```
+    #[cfg(feature = "coarse")]
+    pub fn coarse(mut self, coarse: bool) -> Self {
+        self.coarse = coarse;
+        self
+    }
+
+    /// Refreshes the reading on a background thread every granularity, which
+    /// is stopped when the clock is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ CachedClock, Clock, Utc, };
+    ///
+    /// let clock = CachedClock::new().background();
+    /// let before = Utc::now() - Duration::from_millis(1);
+    /// std::thread::sleep(Duration::from_millis(20));
+    /// assert!(clock.now() > before);
+    /// ```
+    pub fn background(mut self) -> Self {
+        if self.background.is_some() {
+            return self;
+        }
+
+        let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let cached = self.cached.clone();
+        let (granularity, coarse) = (self.granularity, self.coarse);
+        let thread_stop = stop.clone();
+        let thread = std::thread::spawn(move || {
+            while !thread_stop.load(std::sync::atomic::Ordering::Relaxed) {
+                let nanos = wall_nanos(coarse);
+                let mut cached = cached.write()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+                *cached = cached.refresh(nanos, granularity.as_nanos() as i128);
+                drop(cached);
+                std::thread::park_timeout(granularity);
+            }
+        });
+
+        self.background = Some(Background { stop, thread: Some(thread), });
+        self
+    }
+
+    fn granularity_nanos(&self) -> i128 {
+        self.granularity.as_nanos() as i128
+    }
+
+    fn wall_nanos(&self) -> i128 {
+        wall_nanos(self.coarse)
+    }
+
+    fn load(&self) -> Cached {
+        // A reading is always whole, so poisoning is harmless.
+        *self.cached.read().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn store(&self, cached: Cached) {
+        *self.cached.write().unwrap_or_else(std::sync::PoisonError::into_inner) = cached;
+    }
+}
+
+impl Default for CachedClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl crate::Clock for CachedClock {
+    fn now(&self) -> crate::Utc {
+        let cached = self.load();
+        if self.background.is_some() {
+            return cached.utc();
+        }
+
+        let refreshed = cached.refresh(self.wall_nanos(), self.granularity_nanos());
+        if refreshed.tick != cached.tick {
+            // Another thread may have stored a later reading in the meantime.
+            let mut cached = self.cached.write()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if refreshed.tick > cached.tick {
+                *cached = refreshed;
+            }
+        }
+
+        refreshed.utc()
+    }
+}
+
+#[derive(Debug)]
+struct Background {
+    stop: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    thread: Option<std::thread::JoinHandle<()>>,
+}
+
+impl Drop for Background {
+    fn drop(&mut self) {
+        self.stop.store(true, std::sync::atomic::Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Gets the number of nanoseconds since the unix epoch from the wall clock.
+#[cfg_attr(not(all(feature = "coarse", target_os = "linux")), allow(unused_variables))]
+fn wall_nanos(coarse: bool) -> i128 {
+    #[cfg(all(feature = "coarse", target_os = "linux"))]
+    if coarse {
+        let mut ts = libc::timespec { tv_sec: 0, tv_nsec: 0, };
+        // SAFETY: `ts` is a valid timespec for the call to write to.
+        if unsafe { libc::clock_gettime(libc::CLOCK_REALTIME_COARSE, &mut ts) } == 0 {
+            return ts.tv_sec as i128 * 1_000_000_000 + ts.tv_nsec as i128;
+        }
+    }
+
+    std::time::SystemTime::now()
+        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+        .expect("Date must be >= 1970")
+        .as_nanos() as i128
+}
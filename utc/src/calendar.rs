@@ -0,0 +1,164 @@
+//! Proleptic Gregorian calendar utilities.
+//!
+//! # Examples
+//!
+//! ```
+//! use utc::calendar;
+//!
+//! const FEB_DAYS: usize = calendar::days_in_month(2024, 2);
+//! assert_eq!(29, FEB_DAYS);
+//! assert!(!calendar::is_valid_date(2001, 2, 29));
+//! assert_eq!(calendar::Weekday::Tuesday, calendar::weekday_of(2024, 1, 9));
+//! ```
+
+/// A day of the week.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    /// Gets the weekday of a number of days since the unix epoch.
+    pub(crate) const fn from_days_since_epoch(days: i64) -> Self {
+        // The unix epoch started on a Thursday.
+        match days.rem_euclid(super::statics::DIW as i64) {
+            0 => Self::Thursday,
+            1 => Self::Friday,
+            2 => Self::Saturday,
+            3 => Self::Sunday,
+            4 => Self::Monday,
+            5 => Self::Tuesday,
+            _ => Self::Wednesday,
+        }
+    }
+
+    /// Gets the number of days since Monday.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!(0, utc::calendar::Weekday::Monday.days_from_monday());
+    /// assert_eq!(6, utc::calendar::Weekday::Sunday.days_from_monday());
+    /// ```
+    pub const fn days_from_monday(&self) -> usize {
+        *self as usize
+    }
+
+    /// Gets the English name of the day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!("Friday", utc::calendar::Weekday::Friday.name());
+    /// ```
+    pub const fn name(&self) -> &'static str {
+        // Names are stored starting from Thursday.
+        super::statics::D[(self.days_from_monday() + 4) % super::statics::DIW]
+    }
+}
+
+impl core::fmt::Display for Weekday {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmtr.write_str(self.name())
+    }
+}
+
+/// Whether or not the year is a leap year.
+///
+/// # Examples
+///
+/// ```
+/// assert!(utc::calendar::is_leap_year(2000));
+/// assert!(!utc::calendar::is_leap_year(2100));
+/// ```
+pub const fn is_leap_year(year: usize) -> bool {
+    super::statics::ly(year) == 1
+}
+
+/// Gets the number of days in the year.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(366, utc::calendar::days_in_year(2024));
+/// assert_eq!(365, utc::calendar::days_in_year(2023));
+/// ```
+pub const fn days_in_year(year: usize) -> usize {
+    super::statics::DIY[super::statics::ly(year)]
+}
+
+/// Gets the number of days in the month of the year.
+///
+/// # Panics
+///
+/// Panics if the month is not between 1 and 12.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(29, utc::calendar::days_in_month(2024, 2));
+/// assert_eq!(28, utc::calendar::days_in_month(2023, 2));
+/// assert_eq!(31, utc::calendar::days_in_month(2023, 12));
+/// ```
+pub const fn days_in_month(year: usize, month: usize) -> usize {
+    if month < 1 || month > super::statics::MIY {
+        panic!("Month must be between 1 and 12");
+    }
+
+    super::statics::DIM[month - 1][super::statics::ly(year)]
+}
+
+/// Whether or not the month and day exist in the year.
+///
+/// # Examples
+///
+/// ```
+/// assert!(utc::calendar::is_valid_date(2024, 2, 29));
+/// assert!(!utc::calendar::is_valid_date(2023, 2, 29));
+/// assert!(!utc::calendar::is_valid_date(2023, 13, 1));
+/// assert!(!utc::calendar::is_valid_date(2023, 1, 0));
+/// ```
+pub const fn is_valid_date(year: usize, month: usize, day: usize) -> bool {
+    month >= 1 && month <= super::statics::MIY &&
+        day >= 1 && day <= days_in_month(year, month)
+}
+
+/// Gets the day of the week of a date.
+///
+/// Days outside of the month are counted on into the neighbouring months.
+///
+/// # Panics
+///
+/// Panics if the month is not between 1 and 12.
+///
+/// # Examples
+///
+/// ```
+/// use utc::calendar::{ weekday_of, Weekday, };
+///
+/// assert_eq!(Weekday::Thursday, weekday_of(1970, 1, 1));
+/// assert_eq!(Weekday::Tuesday, weekday_of(2024, 1, 9));
+/// assert_eq!(Weekday::Monday, weekday_of(1900, 1, 1));
+/// ```
+pub const fn weekday_of(year: usize, month: usize, day: usize) -> Weekday {
+    if month < 1 || month > super::statics::MIY {
+        panic!("Month must be between 1 and 12");
+    }
+
+    Weekday::from_days_since_epoch(
+        super::statics::days_from_civil(year as i64, month, day)
+    )
+}
@@ -0,0 +1,315 @@
+/// A source of the current date/time.
+///
+/// Code which takes a clock rather than calling [`Utc::now`](crate::Utc::now)
+/// directly can be tested with a [`FixedClock`] or [`MockClock`].
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Clock, FixedClock, Utc, };
+///
+/// fn is_weekend(clock: &impl Clock) -> bool {
+///     clock.now().weekday().days_from_monday() >= 5
+/// }
+///
+/// assert!(is_weekend(&FixedClock::new(Utc::from_ymd(2024, 1, 13))));
+/// assert!(!is_weekend(&FixedClock::new(Utc::from_ymd(2024, 1, 9))));
+/// ```
+pub trait Clock {
+    /// Gets the current date/time according to this clock.
+    fn now(&self) -> crate::Utc;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn now(&self) -> crate::Utc {
+        (**self).now()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Clock + ?Sized> Clock for alloc::boxed::Box<C> {
+    fn now(&self) -> crate::Utc {
+        (**self).now()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Clock + ?Sized> Clock for alloc::sync::Arc<C> {
+    fn now(&self) -> crate::Utc {
+        (**self).now()
+    }
+}
+
+/// The system's wall clock, as used by [`Utc::now`](crate::Utc::now).
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Clock, SystemClock, };
+///
+/// println!("{}", SystemClock.now());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> crate::Utc {
+        crate::Utc::now()
+    }
+}
+
+/// A clock which is stopped at a single date/time.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Clock, FixedClock, Utc, };
+///
+/// let clock = FixedClock::new(Utc::from_ymd(2024, 1, 9));
+/// assert_eq!(Utc::from_ymd(2024, 1, 9), clock.now());
+/// assert_eq!(clock.now(), clock.now());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FixedClock {
+    now: crate::Utc,
+}
+
+impl FixedClock {
+    /// Gets a clock which is always at the date/time.
+    pub const fn new(now: crate::Utc) -> Self {
+        Self { now, }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> crate::Utc {
+        self.now
+    }
+}
+
+/// A clock which only moves when told to.
+///
+/// The clock can be shared between threads, e.g. in an
+/// [`Arc`](std::sync::Arc), and moved by any of them.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::{ Clock, MockClock, Utc, };
+///
+/// let clock = MockClock::new(Utc::from_ymd(2024, 1, 9));
+/// clock.advance(Duration::from_secs(90));
+/// assert_eq!(Utc::from_ymdhms(2024, 1, 9, 0, 1, 30_f64), clock.now());
+///
+/// clock.set(Utc::from_ymd(2023, 12, 31));
+/// assert_eq!(Utc::from_ymd(2023, 12, 31), clock.now());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MockClock {
+    now: std::sync::Mutex<crate::Utc>,
+}
+
+#[cfg(feature = "std")]
+impl MockClock {
+    /// Gets a clock starting at the date/time.
+    pub const fn new(now: crate::Utc) -> Self {
+        Self { now: std::sync::Mutex::new(now), }
+    }
+
+    /// Moves the clock forward.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock would pass the year 9999.
+    pub fn advance(&self, duration: core::time::Duration) {
+        let mut now = self.lock();
+        *now = *now + duration;
+    }
+
+    /// Moves the clock to the date/time, which may be in the past.
+    pub fn set(&self, now: crate::Utc) {
+        *self.lock() = now;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, crate::Utc> {
+        // A date/time cannot be left half-written, so poisoning is harmless.
+        self.now.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for MockClock {
+    fn now(&self) -> crate::Utc {
+        *self.lock()
+    }
+}
+
+/// A clock which runs a fixed amount ahead of or behind another clock.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::{ Clock, FixedClock, OffsetClock, Utc, };
+///
+/// let clock = FixedClock::new(Utc::from_ymd(2024, 1, 9));
+/// let ahead = OffsetClock::ahead(clock, Duration::from_secs(3_600));
+/// assert_eq!(Utc::from_ymdhms(2024, 1, 9, 1, 0, 0_f64), ahead.now());
+///
+/// let behind = OffsetClock::behind(clock, Duration::from_secs(3_600));
+/// assert_eq!(Utc::from_ymdhms(2024, 1, 8, 23, 0, 0_f64), behind.now());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OffsetClock<C> {
+    clock: C,
+    offset: core::time::Duration,
+    behind: bool,
+}
+
+impl<C: Clock> OffsetClock<C> {
+    /// Gets a clock running ahead of another clock.
+    pub const fn ahead(clock: C, offset: core::time::Duration) -> Self {
+        Self { clock, offset, behind: false, }
+    }
+
+    /// Gets a clock running behind another clock.
+    pub const fn behind(clock: C, offset: core::time::Duration) -> Self {
+        Self { clock, offset, behind: true, }
+    }
+
+    /// Gets the clock being skewed.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+}
+
+impl<C: Clock> Clock for OffsetClock<C> {
+    /// # Panics
+    ///
+    /// Panics if the skewed date/time is before 0001 or after 9999.
+    fn now(&self) -> crate::Utc {
+        if self.behind {
+            self.clock.now() - self.offset
+        } else {
+            self.clock.now() + self.offset
+        }
+    }
+}
+
+/// A clock whose readings strictly increase, even across threads.
+///
+/// Each reading is the wall clock's, unless that is not after the previous
+/// reading, e.g. because the wall clock stepped backwards or has not ticked
+/// yet, in which case it is the previous reading plus one nanosecond.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Clock, MonotonicUtc, };
+///
+/// static CLOCK: MonotonicUtc = MonotonicUtc::new();
+///
+/// let a = CLOCK.now();
+/// let b = CLOCK.now();
+/// assert!(a < b);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MonotonicUtc<C = SystemClock> {
+    clock: C,
+    /// Nanoseconds since the unix epoch of the last reading, if any.
+    last: std::sync::Mutex<Option<i128>>,
+}
+
+#[cfg(feature = "std")]
+impl MonotonicUtc {
+    /// Gets a monotonic clock following the system's wall clock.
+    pub const fn new() -> Self {
+        Self::with_clock(SystemClock)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for MonotonicUtc {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock> MonotonicUtc<C> {
+    /// Gets a monotonic clock following another clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ Clock, MockClock, MonotonicUtc, Utc, };
+    ///
+    /// let wall = MockClock::new(Utc::from_ymd(2024, 1, 9));
+    /// let clock = MonotonicUtc::with_clock(&wall);
+    /// let a = clock.now();
+    /// wall.set(Utc::from_ymd(2024, 1, 8));
+    /// assert_eq!(a + Duration::from_nanos(1), clock.now());
+    /// ```
+    pub const fn with_clock(clock: C) -> Self {
+        Self { clock, last: std::sync::Mutex::new(None), }
+    }
+
+    /// Gets how far the last reading is ahead of the wall clock, which is
+    /// zero unless readings have been bumped past it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ Clock, MockClock, MonotonicUtc, Utc, };
+    ///
+    /// let wall = MockClock::new(Utc::from_ymd(2024, 1, 9));
+    /// let clock = MonotonicUtc::with_clock(&wall);
+    /// clock.now();
+    /// assert_eq!(Duration::ZERO, clock.drift());
+    ///
+    /// wall.set(Utc::from_ymdhms(2024, 1, 8, 23, 59, 59_f64));
+    /// clock.now();
+    /// assert_eq!(Duration::new(1, 1), clock.drift());
+    /// ```
+    pub fn drift(&self) -> core::time::Duration {
+        let Some(last) = *self.lock() else {
+            return core::time::Duration::ZERO;
+        };
+        let ahead = (last - self.clock.now().nanos_since_epoch()).max(0);
+        core::time::Duration::new(
+            (ahead / 1_000_000_000) as u64,
+            (ahead % 1_000_000_000) as u32,
+        )
+    }
+
+    /// Gets the clock being followed.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<i128>> {
+        // The last reading is always whole, so poisoning is harmless.
+        self.last.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Clock> Clock for MonotonicUtc<C> {
+    /// # Panics
+    ///
+    /// Panics if a reading would be after 9999.
+    fn now(&self) -> crate::Utc {
+        let mut last = self.lock();
+        let wall = self.clock.now().nanos_since_epoch();
+        let nanos = last.map_or(wall, |last| wall.max(last + 1));
+        *last = Some(nanos);
+        crate::Utc::from_nanos_since_epoch(nanos).expect("Date must be < 10000")
+    }
+}
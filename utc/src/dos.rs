@@ -0,0 +1,137 @@
+/// The number of nanoseconds in the two-second resolution of a DOS time.
+const NANOS_PER_UNIT: i128 = 2_000_000_000;
+
+/// How a date/time between two DOS times is rounded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DosRounding {
+    /// Rounds down to the even second, as `FileTimeToDosDateTime` does.
+    #[default]
+    Down,
+    /// Rounds to the nearest even second, with odd seconds rounded up.
+    Nearest,
+    /// Rounds up to the even second, as Info-ZIP does so that archived
+    /// files are never older than the originals.
+    Up,
+}
+
+/// A DOS date/time, as in FAT directory entries and ZIP headers: a 16-bit
+/// date and a 16-bit time, packed into bit fields.
+///
+/// The date holds the years since 1980 in bits 9 to 15, the month in bits 5
+/// to 8 and the day in bits 0 to 4. The time holds the hour in bits 11 to 15,
+/// the minute in bits 5 to 10 and half the second in bits 0 to 4. DOS
+/// date/times are in local time, but as they carry no time zone they are
+/// read and written here as UTC.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ DosDateTime, DosRounding, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 1_f64);
+/// let dos = DosDateTime::from_utc(&dt, DosRounding::Down).unwrap();
+/// assert_eq!((0x5829, 0x82a0), (dos.date(), dos.time()));
+/// assert_eq!(Ok(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64)), dos.to_utc());
+///
+/// let dos = DosDateTime::from_utc(&dt, DosRounding::Up).unwrap();
+/// assert_eq!(Ok(Utc::from_ymdhms(2024, 1, 9, 16, 21, 2_f64)), dos.to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DosDateTime {
+    date: u16,
+    time: u16,
+}
+
+impl DosDateTime {
+    /// Gets a DOS date/time from its packed date and time.
+    pub const fn new(date: u16, time: u16) -> Self {
+        Self { date, time, }
+    }
+
+    /// Gets the packed date.
+    pub const fn date(&self) -> u16 {
+        self.date
+    }
+
+    /// Gets the packed time.
+    pub const fn time(&self) -> u16 {
+        self.time
+    }
+
+    /// Gets the DOS date/time of a date/time, rounded to an even second, or
+    /// `None` if it is before 1980 or after 2107 once rounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ DosDateTime, DosRounding, Utc, };
+    ///
+    /// let dt = Utc::from_ymdhms(2107, 12, 31, 23, 59, 59_f64);
+    /// assert!(DosDateTime::from_utc(&dt, DosRounding::Nearest).is_none());
+    /// assert!(DosDateTime::from_utc(&dt, DosRounding::Down).is_some());
+    /// ```
+    pub const fn from_utc(dt: &crate::Utc, rounding: DosRounding) -> Option<Self> {
+        let nanos = dt.nanos_since_epoch();
+        let units = match rounding {
+            DosRounding::Down => nanos.div_euclid(NANOS_PER_UNIT),
+            DosRounding::Nearest => (nanos + NANOS_PER_UNIT / 2).div_euclid(NANOS_PER_UNIT),
+            DosRounding::Up => -(-nanos).div_euclid(NANOS_PER_UNIT),
+        };
+        let dt = match crate::Utc::from_nanos_since_epoch(units * NANOS_PER_UNIT) {
+            Some(dt) => dt,
+            None => return None,
+        };
+        if dt.year() < 1980 || dt.year() > 2107 {
+            return None;
+        }
+
+        Some(Self {
+            date: ((dt.year() - 1980) << 9 | dt.month() << 5 | dt.day()) as u16,
+            time: (dt.hour() << 11 | dt.minute() << 5 | (dt.second() / 2)) as u16,
+        })
+    }
+
+    /// Gets the date/time, or the first field which is out of range, e.g.
+    /// the month of the all zero date some writers use for no date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ Component, DosDateTime, };
+    ///
+    /// let err = DosDateTime::new(0, 0).to_utc().unwrap_err();
+    /// assert_eq!(Component::Month, err.component());
+    /// ```
+    pub fn to_utc(&self) -> Result<crate::Utc, crate::RangeError> {
+        let (date, time) = (self.date as usize, self.time as usize);
+        crate::Utc::from_ymdhms_strict(
+            (date >> 9) + 1980, date >> 5 & 0x0f, date & 0x1f,
+            time >> 11, time >> 5 & 0x3f, ((time & 0x1f) * 2) as f64,
+        )
+    }
+
+    /// Encodes the DOS date/time as in ZIP headers and FAT directory
+    /// entries: the time then the date, each little-endian.
+    pub const fn to_bytes(&self) -> [u8; 4] {
+        let (time, date) = (self.time.to_le_bytes(), self.date.to_le_bytes());
+        [time[0], time[1], date[0], date[1]]
+    }
+
+    /// Decodes a DOS date/time as in ZIP headers and FAT directory entries,
+    /// without checking the fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ DosDateTime, Utc, };
+    ///
+    /// let dos = DosDateTime::from_bytes([0xa0, 0x82, 0x29, 0x58]);
+    /// assert_eq!(Ok(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64)), dos.to_utc());
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+        Self {
+            date: u16::from_le_bytes([bytes[2], bytes[3]]),
+            time: u16::from_le_bytes([bytes[0], bytes[1]]),
+        }
+    }
+}
@@ -0,0 +1,327 @@
+const NANOS_PER_MICRO: u128 = 1_000;
+const NANOS_PER_MILLI: u128 = 1_000 * NANOS_PER_MICRO;
+const NANOS_PER_SECOND: u128 = 1_000 * NANOS_PER_MILLI;
+const NANOS_PER_MINUTE: u128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: u128 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: u128 = 24 * NANOS_PER_HOUR;
+#[cfg(feature = "alloc")]
+const NANOS_PER_WEEK: u128 = 7 * NANOS_PER_DAY;
+
+/// The largest number of nanoseconds a [`std::time::Duration`] can hold.
+#[cfg(feature = "alloc")]
+const MAX_NANOS: u128 = u64::MAX as u128 * NANOS_PER_SECOND + 999_999_999;
+
+struct Unit {
+    nanos: u128,
+    compact: &'static str,
+    singular: &'static str,
+    plural: &'static str,
+}
+
+const UNITS: [Unit; 7] = [
+    Unit { nanos: NANOS_PER_DAY, compact: "d", singular: "day", plural: "days", },
+    Unit { nanos: NANOS_PER_HOUR, compact: "h", singular: "hour", plural: "hours", },
+    Unit { nanos: NANOS_PER_MINUTE, compact: "m", singular: "minute", plural: "minutes", },
+    Unit { nanos: NANOS_PER_SECOND, compact: "s", singular: "second", plural: "seconds", },
+    Unit { nanos: NANOS_PER_MILLI, compact: "ms", singular: "millisecond", plural: "milliseconds", },
+    Unit { nanos: NANOS_PER_MICRO, compact: "us", singular: "microsecond", plural: "microseconds", },
+    Unit { nanos: 1, compact: "ns", singular: "nanosecond", plural: "nanoseconds", },
+];
+
+/// The unit names used when displaying a [`HumanDuration`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DurationStyle {
+    /// Abbreviated units separated by spaces, e.g. `1h 30m 5s`.
+    Compact,
+    /// Full unit names separated by commas, e.g. `2 days, 3 hours`.
+    Long,
+}
+
+/// A human-friendly view of a [`std::time::Duration`].
+///
+/// Displays the duration broken into days, hours, minutes, seconds and
+/// sub-second units, skipping any unit which is zero.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::{ DurationStyle, HumanDuration, };
+///
+/// let d = Duration::from_secs(5405);
+/// assert_eq!("1h 30m 5s", HumanDuration::new(d).to_string());
+/// assert_eq!(
+///     "1 hour, 30 minutes",
+///     HumanDuration::new(d)
+///         .largest_units(2)
+///         .style(DurationStyle::Long)
+///         .to_string()
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HumanDuration {
+    duration: core::time::Duration,
+    largest_units: usize,
+    style: DurationStyle,
+}
+
+impl HumanDuration {
+    /// Wraps the duration, showing every unit in the compact style.
+    pub fn new(duration: core::time::Duration) -> Self {
+        Self {
+            duration,
+            largest_units: UNITS.len(),
+            style: DurationStyle::Compact,
+        }
+    }
+
+    /// Wraps the absolute difference between two date/times.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a = utc::Utc::from_ymd(2024, 1, 1);
+    /// let b = utc::Utc::from_ymdhms(2024, 1, 3, 3, 0, 0_f64);
+    /// let d = utc::HumanDuration::between(&a, &b)
+    ///     .style(utc::DurationStyle::Long);
+    /// assert_eq!("2 days, 3 hours", d.to_string());
+    /// ```
+    pub fn between(a: &crate::Utc, b: &crate::Utc) -> Self {
+        Self::new(a.abs_diff(b))
+    }
+
+    /// Limits the output to the given number of units, counted from the
+    /// largest non-zero unit. Smaller units are truncated rather than
+    /// rounded. At least one unit is always shown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let d = std::time::Duration::from_millis(3_723_500);
+    /// let h = utc::HumanDuration::new(d);
+    /// assert_eq!("1h 2m 3s 500ms", h.to_string());
+    /// assert_eq!("1h 2m", h.largest_units(2).to_string());
+    /// ```
+    pub fn largest_units(mut self, units: usize) -> Self {
+        self.largest_units = units.max(1);
+        self
+    }
+
+    /// Sets the style of the unit names.
+    pub fn style(mut self, style: DurationStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Gets the wrapped duration.
+    pub fn duration(&self) -> core::time::Duration {
+        self.duration
+    }
+}
+
+impl From<core::time::Duration> for HumanDuration {
+    fn from(duration: core::time::Duration) -> Self {
+        Self::new(duration)
+    }
+}
+
+impl core::fmt::Display for HumanDuration {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let separator = match self.style {
+            DurationStyle::Compact => " ",
+            DurationStyle::Long => ", ",
+        };
+
+        let mut remaining = self.duration.as_nanos();
+        let first = UNITS.iter()
+            .position(|u| remaining >= u.nanos)
+            .unwrap_or(UNITS.len() - 1);
+        let last = (first + self.largest_units).min(UNITS.len());
+
+        let mut written = false;
+        for unit in &UNITS[first..last] {
+            let count = remaining / unit.nanos;
+            remaining -= count * unit.nanos;
+            if count == 0 {
+                continue;
+            }
+
+            if written {
+                fmtr.write_str(separator)?;
+            }
+
+            match self.style {
+                DurationStyle::Compact => write!(fmtr, "{count}{}", unit.compact)?,
+                DurationStyle::Long if count == 1 => write!(fmtr, "1 {}", unit.singular)?,
+                DurationStyle::Long => write!(fmtr, "{count} {}", unit.plural)?,
+            }
+
+            written = true;
+        }
+
+        if !written {
+            match self.style {
+                DurationStyle::Compact => fmtr.write_str("0s")?,
+                DurationStyle::Long => fmtr.write_str("0 seconds")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for HumanDuration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_duration(s).map(Self::new)
+    }
+}
+
+/// An error encountered while parsing a duration.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseDurationError {
+    /// The input contained no durations.
+    Empty,
+    /// A number was expected at the given byte offset.
+    InvalidNumber(usize),
+    /// A number at the given byte offset was not followed by a unit.
+    MissingUnit(usize),
+    /// The unit at the given byte offset is not recognized.
+    UnknownUnit(usize, alloc::string::String),
+    /// The total does not fit in a [`std::time::Duration`].
+    Overflow,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for ParseDurationError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Empty => fmtr.write_str("empty duration"),
+            Self::InvalidNumber(at) => write!(fmtr, "expected a number at byte {at}"),
+            Self::MissingUnit(at) => write!(fmtr, "expected a unit at byte {at}"),
+            Self::UnknownUnit(at, unit) => write!(fmtr, "unknown unit \"{unit}\" at byte {at}"),
+            Self::Overflow => fmtr.write_str("duration is too large"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for ParseDurationError {}
+
+#[cfg(feature = "alloc")]
+fn unit_nanos(unit: &str) -> Option<u128> {
+    Some(match unit.to_ascii_lowercase().as_str() {
+        "w" | "wk" | "wks" | "week" | "weeks" => NANOS_PER_WEEK,
+        "d" | "day" | "days" => NANOS_PER_DAY,
+        "h" | "hr" | "hrs" | "hour" | "hours" => NANOS_PER_HOUR,
+        "m" | "min" | "mins" | "minute" | "minutes" => NANOS_PER_MINUTE,
+        "s" | "sec" | "secs" | "second" | "seconds" => NANOS_PER_SECOND,
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => NANOS_PER_MILLI,
+        "us" | "µs" | "usec" | "usecs" | "microsecond" | "microseconds" => NANOS_PER_MICRO,
+        "ns" | "nsec" | "nsecs" | "nanosecond" | "nanoseconds" => 1,
+        _ => return None,
+    })
+}
+
+/// Parses a human-friendly duration.
+///
+/// The input is a sequence of numbers each followed by a unit, optionally
+/// separated by whitespace or commas. Numbers may have a fractional part.
+/// Units may be abbreviated (`w`, `d`, `h`, `m`, `s`, `ms`, `us`, `ns`) or
+/// spelled out (`weeks`, `days`, `hours`, ...). Every format produced by
+/// [`HumanDuration`] can be parsed.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// assert_eq!(Duration::from_secs(90), utc::parse_duration("90s").unwrap());
+/// assert_eq!(Duration::from_secs(5400), utc::parse_duration("1.5h").unwrap());
+/// assert_eq!(Duration::from_secs(302_400), utc::parse_duration("3d12h").unwrap());
+/// assert_eq!(
+///     Duration::from_secs(183_600),
+///     utc::parse_duration("2 days, 3 hours").unwrap()
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+pub fn parse_duration(input: &str) -> Result<core::time::Duration, ParseDurationError> {
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    let mut total: u128 = 0;
+    let mut any = false;
+
+    loop {
+        while i < bytes.len() && (bytes[i].is_ascii_whitespace() || bytes[i] == b',') {
+            i += 1;
+        }
+        if i == bytes.len() {
+            break;
+        }
+
+        // Number with an optional fractional part.
+        let number_start = i;
+        let mut whole: u128 = 0;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            whole = whole.checked_mul(10)
+                .and_then(|w| w.checked_add((bytes[i] - b'0') as u128))
+                .ok_or(ParseDurationError::Overflow)?;
+            i += 1;
+        }
+        let mut digits = i - number_start;
+        let mut fraction: u128 = 0;
+        let mut scale: u128 = 1;
+        if i < bytes.len() && bytes[i] == b'.' {
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                // Digits beyond attosecond precision cannot matter.
+                if scale < 1_000_000_000_000_000_000 {
+                    fraction = fraction * 10 + (bytes[i] - b'0') as u128;
+                    scale *= 10;
+                }
+                digits += 1;
+                i += 1;
+            }
+        }
+        if digits == 0 {
+            return Err(ParseDurationError::InvalidNumber(number_start));
+        }
+
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+
+        let unit_start = i;
+        let unit_end = input[unit_start..]
+            .find(|c: char| !c.is_alphabetic())
+            .map(|n| unit_start + n)
+            .unwrap_or(input.len());
+        if unit_start == unit_end {
+            return Err(ParseDurationError::MissingUnit(unit_start));
+        }
+        let unit = &input[unit_start..unit_end];
+        let nanos = unit_nanos(unit).ok_or_else(|| {
+            ParseDurationError::UnknownUnit(unit_start, unit.into())
+        })?;
+        i = unit_end;
+
+        total = whole.checked_mul(nanos)
+            .and_then(|w| w.checked_add(fraction * nanos / scale))
+            .and_then(|n| total.checked_add(n))
+            .filter(|n| *n <= MAX_NANOS)
+            .ok_or(ParseDurationError::Overflow)?;
+        any = true;
+    }
+
+    if !any {
+        return Err(ParseDurationError::Empty);
+    }
+
+    Ok(core::time::Duration::new(
+        (total / NANOS_PER_SECOND) as u64,
+        (total % NANOS_PER_SECOND) as u32,
+    ))
+}
@@ -0,0 +1,135 @@
+/// A timestamp found in text by [`find_timestamps`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FoundTimestamp {
+    span: core::ops::Range<usize>,
+    format: crate::Format,
+    utc: crate::Utc,
+}
+
+impl FoundTimestamp {
+    /// Gets the byte range of the timestamp within the text.
+    pub fn span(&self) -> core::ops::Range<usize> {
+        self.span.clone()
+    }
+
+    /// Gets the format the timestamp was written in.
+    pub fn format(&self) -> crate::Format {
+        self.format
+    }
+
+    /// Gets the parsed date/time.
+    pub fn utc(&self) -> crate::Utc {
+        self.utc
+    }
+}
+
+/// Finds every timestamp in arbitrary text.
+///
+/// The hinted formats are tried in order at the start of every word. Only
+/// timestamps with a time of day are reported, so bare dates are left
+/// alone, and [`Format::Epoch`](crate::Format::Epoch) values are only
+/// recognized between square brackets, e.g. `[1704817260]`, with at least
+/// nine digits and not straight after a name, as in `sshd[1234]`. Numeric dates
+/// which are ambiguous under the hints are skipped. Spans never include
+/// surrounding brackets.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ find_timestamps, Format, ParseHints, Utc, };
+///
+/// let text = "Jan  9 16:21:00 host sshd: accepted at [1704817261.5]";
+/// let found = find_timestamps(text, &ParseHints::new().year(2024));
+/// assert_eq!(2, found.len());
+/// assert_eq!(0..15, found[0].span());
+/// assert_eq!(Format::Syslog, found[0].format());
+/// assert_eq!(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64), found[0].utc());
+/// assert_eq!(40..52, found[1].span());
+/// assert_eq!(Format::Epoch, found[1].format());
+/// ```
+pub fn find_timestamps(text: &str, hints: &crate::ParseHints) -> alloc::vec::Vec<FoundTimestamp> {
+    let bytes = text.as_bytes();
+    let mut found = alloc::vec::Vec::new();
+    let year = hints.year_to_assume();
+    let mut start = 0;
+
+    while start < bytes.len() {
+        let at_word_start = text.is_char_boundary(start) &&
+            (start == 0 || !bytes[start - 1].is_ascii_alphanumeric());
+        let timestamp = at_word_start
+            .then(|| timestamp_at(bytes, start, hints, year))
+            .flatten();
+
+        match timestamp {
+            Some(timestamp) => {
+                start = timestamp.span.end;
+                found.push(timestamp);
+            },
+            None => start += 1,
+        }
+    }
+
+    found
+}
+
+/// The fewest digits of a [`Format::Epoch`](crate::Format::Epoch) value
+/// found in text, so from 1973-03-03 in seconds.
+const MIN_EPOCH_DIGITS: usize = 9;
+
+fn timestamp_at(
+    bytes: &[u8], start: usize, hints: &crate::ParseHints, year: Option<usize>,
+) -> Option<FoundTimestamp> {
+    // A bracket straight after an identifier, e.g. `sshd[1234]`, holds a
+    // PID or an index, and fewer than `MIN_EPOCH_DIGITS` digits are more
+    // likely a count than a timestamp.
+    let bracketed = start > 0 && bytes[start - 1] == b'[' &&
+        (start < 2 || !(bytes[start - 2].is_ascii_alphanumeric() || bytes[start - 2] == b'_'));
+    let digits = bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+    for format in hints.formats_to_try() {
+        if *format == crate::Format::Epoch && (!bracketed || digits < MIN_EPOCH_DIGITS) {
+            continue;
+        }
+
+        let mut cursor = crate::parse::Cursor::new(bytes, start);
+        let parsed = crate::parse::parse_format(*format, &mut cursor, hints, year);
+        let end = cursor.pos();
+        let at_word_end = bytes.get(end).is_none_or(|b| !b.is_ascii_alphanumeric());
+        let closed = *format != crate::Format::Epoch || bytes.get(end) == Some(&b']');
+        let timed = cursor.timed() || *format == crate::Format::Epoch;
+
+        if let Some(crate::parse::Parsed::Valid(utc)) = parsed {
+            if at_word_end && closed && timed {
+                return Some(FoundTimestamp { span: start..end, format: *format, utc, });
+            }
+        }
+    }
+
+    None
+}
+
+/// Rewrites every timestamp found by [`find_timestamps`] in
+/// [`Utc::as_rfc3339_nano`](crate::Utc::as_rfc3339_nano) form.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ normalize_timestamps, ParseHints, };
+///
+/// let text = r#"127.0.0.1 - - [09/Jan/2024:17:21:00 +0100] "GET / HTTP/1.1" 200"#;
+/// assert_eq!(
+///     r#"127.0.0.1 - - [2024-01-09T16:21:00.000000] "GET / HTTP/1.1" 200"#,
+///     normalize_timestamps(text, &ParseHints::new())
+/// );
+/// ```
+pub fn normalize_timestamps(text: &str, hints: &crate::ParseHints) -> alloc::string::String {
+    let mut normalized = alloc::string::String::with_capacity(text.len());
+    let mut copied = 0;
+    for timestamp in find_timestamps(text, hints) {
+        normalized.push_str(&text[copied..timestamp.span.start]);
+        normalized.push_str(&timestamp.utc.as_rfc3339_nano());
+        copied = timestamp.span.end;
+    }
+    normalized.push_str(&text[copied..]);
+
+    normalized
+}
@@ -0,0 +1,222 @@
+/// The most fractional digits a [`FormatBuf`] holds.
+pub(crate) const MAX_PRECISION: usize = 9;
+
+/// A `.` followed by the fraction of a second truncated to a precision,
+/// with zeros past the nanoseconds, or nothing for a precision of zero.
+pub(crate) struct Fraction {
+    nanosecond: u32,
+    precision: usize,
+}
+
+impl Fraction {
+    pub(crate) fn new(dt: &crate::Utc, precision: usize) -> Self {
+        Self { nanosecond: dt.nanosecond(), precision, }
+    }
+
+    /// Gets the number of bytes written.
+    pub(crate) const fn len(&self) -> usize {
+        if self.precision == 0 { 0 } else { self.precision + 1 }
+    }
+
+    /// Gets the digit at an index after the decimal point.
+    const fn digit(&self, index: usize) -> u8 {
+        if index >= MAX_PRECISION {
+            return b'0';
+        }
+
+        let divisor = 10_u32.pow((MAX_PRECISION - 1 - index) as u32);
+        b'0' + (self.nanosecond / divisor % 10) as u8
+    }
+}
+
+impl core::fmt::Display for Fraction {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.precision == 0 {
+            return Ok(());
+        }
+
+        fmtr.write_str(".")?;
+        for index in 0..self.precision {
+            core::fmt::Write::write_char(fmtr, self.digit(index) as char)?;
+        }
+        Ok(())
+    }
+}
+
+/// A formatted date/time held in a fixed-size buffer on the stack.
+///
+/// # Examples
+///
+/// ```
+/// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let buf = dt.to_rfc3339_with_nano_buf(3);
+/// assert_eq!("2024-01-09T16:21:00.500", buf.as_str());
+/// assert_eq!(23, buf.len());
+/// ```
+#[derive(Clone, Copy)]
+pub struct FormatBuf {
+    bytes: [u8; FormatBuf::CAPACITY],
+    len: usize,
+}
+
+impl FormatBuf {
+    /// Enough for the longest format, `Tue, 09 Jan 2024 12:05:57 GMT` or
+    /// `2024-01-09T12:05:57.123456789`.
+    const CAPACITY: usize = 32;
+
+    pub(crate) const fn new() -> Self {
+        Self { bytes: [0; Self::CAPACITY], len: 0, }
+    }
+
+    /// Gets the formatted date/time.
+    pub fn as_str(&self) -> &str {
+        // Only ASCII is ever pushed.
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap()
+    }
+
+    /// Gets the formatted date/time as bytes.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.bytes[self.len] = byte;
+        self.len += 1;
+    }
+
+    fn push_str(&mut self, s: &str) {
+        self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+    }
+
+    /// Pushes the last `width` digits of the value, zero padded.
+    fn push_digits(&mut self, mut value: usize, width: usize) {
+        for i in (0..width).rev() {
+            self.bytes[self.len + i] = b'0' + (value % 10) as u8;
+            value /= 10;
+        }
+        self.len += width;
+    }
+
+    /// Pushes yyyy-MM-dd.
+    pub(crate) fn push_date(&mut self, dt: &crate::Utc) {
+        self.push_digits(dt.year(), 4);
+        self.push(b'-');
+        self.push_digits(dt.month(), 2);
+        self.push(b'-');
+        self.push_digits(dt.day(), 2);
+    }
+
+    /// Pushes the separator followed by HH:mm:ss.
+    pub(crate) fn push_time(&mut self, dt: &crate::Utc, separator: u8) {
+        self.push(separator);
+        self.push_digits(dt.hour(), 2);
+        self.push(b':');
+        self.push_digits(dt.minute(), 2);
+        self.push(b':');
+        self.push_digits(dt.second(), 2);
+    }
+
+    /// Pushes a [`Fraction`] of at most [`MAX_PRECISION`] digits.
+    pub(crate) fn push_fraction(&mut self, dt: &crate::Utc, precision: usize) {
+        let fraction = Fraction::new(dt, precision.min(MAX_PRECISION));
+        if fraction.precision == 0 {
+            return;
+        }
+
+        self.push(b'.');
+        for index in 0..fraction.precision {
+            self.push(fraction.digit(index));
+        }
+    }
+
+    /// Pushes Day, dd Mon yyyy HH:mm:ss GMT.
+    pub(crate) fn push_rfc7231(&mut self, dt: &crate::Utc) {
+        self.push_str(&dt.day_of_week()[0..3]);
+        self.push_str(", ");
+        self.push_digits(dt.day(), 2);
+        self.push(b' ');
+        self.push_str(&dt.month_of_year()[0..3]);
+        self.push(b' ');
+        self.push_digits(dt.year(), 4);
+        self.push_time(dt, b' ');
+        self.push_str(" GMT");
+    }
+}
+
+impl core::ops::Deref for FormatBuf {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for FormatBuf {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl PartialEq<str> for FormatBuf {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for FormatBuf {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<FormatBuf> for str {
+    fn eq(&self, other: &FormatBuf) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<FormatBuf> for &str {
+    fn eq(&self, other: &FormatBuf) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl core::fmt::Debug for FormatBuf {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), fmtr)
+    }
+}
+
+impl core::fmt::Display for FormatBuf {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmtr.pad(self.as_str())
+    }
+}
+
+/// Pads text of a length written by `write` to the formatter's width, with
+/// its fill and alignment, left by default.
+///
+/// `Formatter::pad` would truncate to the precision, which date/times use
+/// for the digits of the fraction instead.
+pub(crate) fn pad(
+    fmtr: &mut core::fmt::Formatter, len: usize,
+    write: impl FnOnce(&mut core::fmt::Formatter) -> core::fmt::Result,
+) -> core::fmt::Result {
+    let padding = fmtr.width().unwrap_or(0).saturating_sub(len);
+    let (before, after) = match fmtr.align() {
+        Some(core::fmt::Alignment::Right) => (padding, 0),
+        Some(core::fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (0, padding),
+    };
+
+    for _ in 0..before {
+        core::fmt::Write::write_char(fmtr, fmtr.fill())?;
+    }
+    write(fmtr)?;
+    for _ in 0..after {
+        core::fmt::Write::write_char(fmtr, fmtr.fill())?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,313 @@
+/// The largest number of milliseconds since the unix epoch before 10000.
+const MAX_MILLIS: u64 = crate::Utc::MAX.millis_since_epoch();
+
+/// A hybrid logical clock timestamp: a physical time to the millisecond
+/// plus a logical counter ordering events within the same millisecond.
+///
+/// Timestamps are totally ordered by physical time and then by counter.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ HlcTimestamp, Utc, };
+///
+/// let a = HlcTimestamp::new(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64), 0);
+/// let b = HlcTimestamp::new(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64), 1);
+/// assert!(a < b);
+/// assert_eq!("2024-01-09T16:21:00.500Z-00001", b.to_string());
+/// assert_eq!(Ok(b), "2024-01-09T16:21:00.500Z-00001".parse());
+/// assert_eq!(Some(b), HlcTimestamp::from_u64(b.to_u64()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HlcTimestamp {
+    millis: u64,
+    logical: u16,
+}
+
+impl HlcTimestamp {
+    /// The earliest timestamp, at the unix epoch.
+    pub const MIN: Self = Self { millis: 0, logical: 0, };
+
+    /// Gets a timestamp from a physical time, truncated to the millisecond
+    /// and taken as the unix epoch if before it, and a logical counter.
+    pub const fn new(physical: crate::Utc, logical: u16) -> Self {
+        Self { millis: physical.millis_since_epoch(), logical, }
+    }
+
+    /// Gets the physical time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ HlcTimestamp, Utc, };
+    ///
+    /// let ts = HlcTimestamp::new(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.1234_f64), 7);
+    /// assert_eq!("2024-01-09T16:21:00.123", ts.physical().to_rfc3339_with_nano_buf(3));
+    /// ```
+    pub const fn physical(&self) -> crate::Utc {
+        match crate::Utc::from_epoch_millis(self.millis) {
+            Some(physical) => physical,
+            None => panic!("Date must be < 10000"),
+        }
+    }
+
+    /// Gets the logical counter.
+    pub const fn logical(&self) -> u16 {
+        self.logical
+    }
+
+    /// Encodes the timestamp in 64 bits: the milliseconds since the unix
+    /// epoch in the upper 48 and the counter in the lower 16.
+    ///
+    /// Encoded timestamps order the same as the timestamps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ HlcTimestamp, Utc, };
+    ///
+    /// let ts = HlcTimestamp::new(Utc::from_ymdhms(1970, 1, 1, 0, 0, 1_f64), 2);
+    /// assert_eq!(1_000 << 16 | 2, ts.to_u64());
+    /// ```
+    pub const fn to_u64(&self) -> u64 {
+        self.millis << 16 | self.logical as u64
+    }
+
+    /// Decodes a timestamp encoded by [`HlcTimestamp::to_u64`], or `None`
+    /// if its physical time is in the year 10000 or later.
+    pub const fn from_u64(encoded: u64) -> Option<Self> {
+        let millis = encoded >> 16;
+        if millis > MAX_MILLIS {
+            return None;
+        }
+
+        Some(Self { millis, logical: encoded as u16, })
+    }
+}
+
+/// Formats as the physical time in RFC 3339 form to the millisecond with a
+/// `Z` offset, followed by `-` and the five digit counter.
+///
+/// The text sorts in the same order as the timestamps.
+impl core::fmt::Display for HlcTimestamp {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            fmtr, "{}Z-{:05}",
+            self.physical().to_rfc3339_with_nano_buf(3), self.logical,
+        )
+    }
+}
+
+impl core::str::FromStr for HlcTimestamp {
+    type Err = ParseHlcError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // yyyy-MM-ddTHH:mm:ss.fffZ-ccccc
+        let bytes = s.as_bytes();
+        if bytes.len() != 30 {
+            return Err(ParseHlcError);
+        }
+
+        let separators = [
+            (4, b'-'), (7, b'-'), (10, b'T'), (13, b':'),
+            (16, b':'), (19, b'.'), (23, b'Z'), (24, b'-'),
+        ];
+        if separators.iter().any(|(i, separator)| bytes[*i] != *separator) {
+            return Err(ParseHlcError);
+        }
+
+        let number = |start: usize, end: usize| -> Result<u64, ParseHlcError> {
+            bytes[start..end].iter().try_fold(0_u64, |n, b| match b {
+                b'0'..=b'9' => Ok(n * 10 + (b - b'0') as u64),
+                _ => Err(ParseHlcError),
+            })
+        };
+
+        let physical = crate::Utc::from_ymdhms_strict(
+            number(0, 4)? as usize, number(5, 7)? as usize, number(8, 10)? as usize,
+            number(11, 13)? as usize, number(14, 16)? as usize,
+            number(17, 19)? as f64 + number(20, 23)? as f64 / 1_000_f64,
+        ).map_err(|_| ParseHlcError)?;
+        let logical = u16::try_from(number(25, 30)?).map_err(|_| ParseHlcError)?;
+
+        Ok(Self::new(physical, logical))
+    }
+}
+
+/// An error encountered while parsing an [`HlcTimestamp`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseHlcError;
+
+impl core::fmt::Display for ParseHlcError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmtr.write_str("invalid hybrid logical clock timestamp")
+    }
+}
+
+impl core::error::Error for ParseHlcError {}
+
+/// An error encountered while updating a [`HybridLogicalClock`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HlcError {
+    /// A received timestamp was further ahead of the physical clock than
+    /// the maximum tolerated skew.
+    SkewExceeded {
+        /// The received timestamp.
+        remote: HlcTimestamp,
+        /// How far the received timestamp was ahead.
+        skew: core::time::Duration,
+    },
+    /// The logical counter cannot count any more events in the same
+    /// millisecond.
+    LogicalOverflow,
+}
+
+impl core::fmt::Display for HlcError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::SkewExceeded { remote, skew, } => write!(
+                fmtr, "timestamp {remote} is {skew:?} ahead of the physical clock"
+            ),
+            Self::LogicalOverflow => fmtr.write_str("logical counter overflowed"),
+        }
+    }
+}
+
+impl core::error::Error for HlcError {}
+
+/// A hybrid logical clock, giving timestamps which respect causality
+/// between nodes while staying close to physical time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::{ FixedClock, HybridLogicalClock, Utc, };
+///
+/// let node_a = HybridLogicalClock::with_clock(FixedClock::new(Utc::from_ymd(2024, 1, 9)));
+/// let node_b = HybridLogicalClock::with_clock(FixedClock::new(Utc::from_ymd(2024, 1, 8)))
+///     .max_skew(Duration::from_secs(86_400));
+///
+/// // Node b's clock is behind, but its timestamps still follow node a's.
+/// let sent = node_a.send().unwrap();
+/// let received = node_b.receive(sent).unwrap();
+/// assert!(sent < received);
+/// assert!(received < node_b.send().unwrap());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct HybridLogicalClock<C = crate::SystemClock> {
+    clock: C,
+    max_skew: Option<core::time::Duration>,
+    last: std::sync::Mutex<HlcTimestamp>,
+}
+
+#[cfg(feature = "std")]
+impl HybridLogicalClock {
+    /// Gets a hybrid logical clock following the system's wall clock.
+    pub const fn new() -> Self {
+        Self::with_clock(crate::SystemClock)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for HybridLogicalClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: crate::Clock> HybridLogicalClock<C> {
+    /// Gets a hybrid logical clock following another clock as its physical
+    /// clock.
+    pub const fn with_clock(clock: C) -> Self {
+        Self {
+            clock,
+            max_skew: None,
+            last: std::sync::Mutex::new(HlcTimestamp::MIN),
+        }
+    }
+
+    /// Sets how far ahead of the physical clock a received timestamp may be.
+    /// Any skew is tolerated by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ FixedClock, HlcError, HlcTimestamp, HybridLogicalClock, Utc, };
+    ///
+    /// let clock = HybridLogicalClock::with_clock(FixedClock::new(Utc::from_ymd(2024, 1, 9)))
+    ///     .max_skew(Duration::from_millis(500));
+    /// let remote = HlcTimestamp::new(Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64), 0);
+    /// assert_eq!(
+    ///     Err(HlcError::SkewExceeded { remote, skew: Duration::from_secs(1), }),
+    ///     clock.receive(remote)
+    /// );
+    /// ```
+    pub fn max_skew(mut self, max_skew: core::time::Duration) -> Self {
+        self.max_skew = Some(max_skew);
+        self
+    }
+
+    /// Gets the timestamp of a local or send event.
+    pub fn send(&self) -> Result<HlcTimestamp, HlcError> {
+        let physical = self.physical_millis();
+        let mut last = self.lock();
+        *last = Self::tick(&[*last], physical)?;
+        Ok(*last)
+    }
+
+    /// Gets the timestamp of receiving a message sent at the remote
+    /// timestamp, which is after both it and every earlier event here.
+    ///
+    /// The clock is left unchanged if the remote timestamp is too far ahead.
+    pub fn receive(&self, remote: HlcTimestamp) -> Result<HlcTimestamp, HlcError> {
+        let physical = self.physical_millis();
+        if let Some(max_skew) = self.max_skew {
+            let skew = core::time::Duration::from_millis(
+                remote.millis.saturating_sub(physical)
+            );
+            if skew > max_skew {
+                return Err(HlcError::SkewExceeded { remote, skew, });
+            }
+        }
+
+        let mut last = self.lock();
+        *last = Self::tick(&[*last, remote], physical)?;
+        Ok(*last)
+    }
+
+    /// Gets the last timestamp given out, or [`HlcTimestamp::MIN`] if none
+    /// has been.
+    pub fn last(&self) -> HlcTimestamp {
+        *self.lock()
+    }
+
+    /// Gets the clock used as the physical clock.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+
+    /// Gets the timestamp after all of the previous timestamps, keeping the
+    /// physical time if possible.
+    fn tick(previous: &[HlcTimestamp], physical: u64) -> Result<HlcTimestamp, HlcError> {
+        let latest = previous.iter().max().copied().unwrap_or(HlcTimestamp::MIN);
+        if physical > latest.millis {
+            return Ok(HlcTimestamp { millis: physical, logical: 0, });
+        }
+
+        let logical = latest.logical.checked_add(1).ok_or(HlcError::LogicalOverflow)?;
+        Ok(HlcTimestamp { millis: latest.millis, logical, })
+    }
+
+    fn physical_millis(&self) -> u64 {
+        self.clock.now().millis_since_epoch()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HlcTimestamp> {
+        // The last timestamp is always whole, so poisoning is harmless.
+        self.last.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,333 @@
+/// Seconds from the NTP epoch, 1900-01-01, to the unix epoch.
+pub(crate) const NTP_UNIX_OFFSET: u64 = 2_208_988_800;
+
+/// The leap second table from the IERS `leap-seconds.list`, as NTP seconds
+/// at which TAI − UTC became the given number of seconds.
+const EMBEDDED: [(u64, i32); 28] = [
+    (2_272_060_800, 10), // 1 Jan 1972
+    (2_287_785_600, 11), // 1 Jul 1972
+    (2_303_683_200, 12), // 1 Jan 1973
+    (2_335_219_200, 13), // 1 Jan 1974
+    (2_366_755_200, 14), // 1 Jan 1975
+    (2_398_291_200, 15), // 1 Jan 1976
+    (2_429_913_600, 16), // 1 Jan 1977
+    (2_461_449_600, 17), // 1 Jan 1978
+    (2_492_985_600, 18), // 1 Jan 1979
+    (2_524_521_600, 19), // 1 Jan 1980
+    (2_571_782_400, 20), // 1 Jul 1981
+    (2_603_318_400, 21), // 1 Jul 1982
+    (2_634_854_400, 22), // 1 Jul 1983
+    (2_698_012_800, 23), // 1 Jul 1985
+    (2_776_982_400, 24), // 1 Jan 1988
+    (2_840_140_800, 25), // 1 Jan 1990
+    (2_871_676_800, 26), // 1 Jan 1991
+    (2_918_937_600, 27), // 1 Jul 1992
+    (2_950_473_600, 28), // 1 Jul 1993
+    (2_982_009_600, 29), // 1 Jul 1994
+    (3_029_443_200, 30), // 1 Jan 1996
+    (3_076_704_000, 31), // 1 Jul 1997
+    (3_124_137_600, 32), // 1 Jan 1999
+    (3_345_062_400, 33), // 1 Jan 2006
+    (3_439_756_800, 34), // 1 Jan 2009
+    (3_550_089_600, 35), // 1 Jul 2012
+    (3_644_697_600, 36), // 1 Jul 2015
+    (3_692_217_600, 37), // 1 Jan 2017
+];
+
+/// Whether or not the day in the embedded table ends with a leap second.
+pub(crate) const fn is_embedded_leap_day(year: usize, month: usize, day: usize) -> bool {
+    has_leap_second(&EMBEDDED, year, month, day)
+}
+
+const fn has_leap_second(entries: &[(u64, i32)], year: usize, month: usize, day: usize) -> bool {
+    if year > super::statics::MAX_Y || !crate::calendar::is_valid_date(year, month, day) {
+        return false;
+    }
+
+    let next_day = (super::statics::days_from_civil(year as i64, month, day) + 1) * 86_400;
+    if next_day < 0 {
+        return false;
+    }
+
+    let ntp = next_day as u64 + NTP_UNIX_OFFSET;
+    let mut i = 1;
+    while i < entries.len() {
+        if entries[i].0 == ntp {
+            return entries[i].1 > entries[i - 1].1;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// A table of leap seconds, giving the difference between TAI and UTC.
+///
+/// The [embedded](LeapSeconds::embedded) table covers every leap second up
+/// to the time of release. As leap seconds are announced by the IERS, an
+/// updated `leap-seconds.list` can be [loaded](LeapSeconds::load) instead.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ LeapSeconds, Utc, };
+///
+/// let table = LeapSeconds::embedded();
+/// assert!(table.has_leap_second(2016, 12, 31));
+/// assert!(!table.has_leap_second(2017, 12, 31));
+/// assert_eq!(37, table.tai_offset(&Utc::from_ymd(2024, 1, 9)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeapSeconds {
+    entries: Entries,
+    expires: Option<u64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Entries {
+    Embedded(&'static [(u64, i32)]),
+    #[cfg(feature = "alloc")]
+    Loaded(alloc::vec::Vec<(u64, i32)>),
+}
+
+impl LeapSeconds {
+    /// Gets the table embedded in the crate.
+    pub const fn embedded() -> Self {
+        Self { entries: Entries::Embedded(&EMBEDDED), expires: None, }
+    }
+
+    /// Parses the contents of an IERS `leap-seconds.list` file.
+    ///
+    /// Each line holds the NTP seconds at which TAI − UTC changed and its
+    /// new value. Comments start with `#`, except for `#@` which gives the
+    /// expiry of the table. The `#h` hash is not checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, Utc, };
+    ///
+    /// let list = "\
+    /// #@\t3960057600
+    /// 2272060800\t10\t# 1 Jan 1972
+    /// 2287785600\t11\t# 1 Jul 1972
+    /// ";
+    /// let table = LeapSeconds::parse(list).unwrap();
+    /// assert!(table.has_leap_second(1972, 6, 30));
+    /// assert_eq!(Some(Utc::from_ymd(2025, 6, 28)), table.expires());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse(list: &str) -> Result<Self, LeapSecondsError> {
+        let mut entries: alloc::vec::Vec<(u64, i32)> = alloc::vec::Vec::new();
+        let mut expires = None;
+        for (i, line) in list.lines().enumerate() {
+            let line_number = i + 1;
+            if let Some(expiry) = line.strip_prefix("#@") {
+                let expiry = expiry.trim().parse::<u64>()
+                    .map_err(|_| LeapSecondsError::InvalidLine(line_number))?;
+                expires = Some(expiry);
+                continue;
+            }
+
+            let data = line.split('#').next().unwrap_or("");
+            let mut fields = data.split_whitespace();
+            let (ntp, offset) = match (fields.next(), fields.next()) {
+                (None, _) => continue,
+                (Some(ntp), Some(offset)) => (ntp, offset),
+                (Some(_), None) => return Err(LeapSecondsError::InvalidLine(line_number)),
+            };
+            let entry = ntp.parse::<u64>().ok()
+                .zip(offset.parse::<i32>().ok())
+                .filter(|(ntp, _)| *ntp >= NTP_UNIX_OFFSET)
+                .ok_or(LeapSecondsError::InvalidLine(line_number))?;
+            if entries.last().is_some_and(|last| last.0 >= entry.0) {
+                return Err(LeapSecondsError::NotIncreasing(line_number));
+            }
+            entries.push(entry);
+        }
+
+        if entries.is_empty() {
+            return Err(LeapSecondsError::Empty);
+        }
+
+        Ok(Self { entries: Entries::Loaded(entries), expires, })
+    }
+
+    /// Reads and parses an IERS `leap-seconds.list` file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// let table = utc::LeapSeconds::load("/usr/share/zoneinfo/leap-seconds.list").unwrap();
+    /// println!("{:?}", table.expires());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, LeapSecondsError> {
+        Self::parse(&std::fs::read_to_string(path).map_err(LeapSecondsError::Io)?)
+    }
+
+    fn entries(&self) -> &[(u64, i32)] {
+        match &self.entries {
+            Entries::Embedded(entries) => entries,
+            #[cfg(feature = "alloc")]
+            Entries::Loaded(entries) => entries,
+        }
+    }
+
+    /// Gets when the table stops being valid, if it was loaded with an
+    /// expiry.
+    pub fn expires(&self) -> Option<crate::Utc> {
+        crate::Utc::from_epoch_seconds(self.expires?.checked_sub(NTP_UNIX_OFFSET)?)
+    }
+
+    /// Whether or not the day ends with a leap second, 23:59:60.
+    pub fn has_leap_second(&self, year: usize, month: usize, day: usize) -> bool {
+        has_leap_second(self.entries(), year, month, day)
+    }
+
+    /// Gets TAI − UTC in seconds at the date/time.
+    ///
+    /// During a leap second the offset is still the one from before it.
+    /// Before 1972 the offset was not a whole number of seconds, so the
+    /// first offset in the table is given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, Utc, };
+    ///
+    /// let table = LeapSeconds::embedded();
+    /// assert_eq!(36, table.tai_offset(&Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64)));
+    /// assert_eq!(37, table.tai_offset(&Utc::from_ymd(2017, 1, 1)));
+    /// ```
+    pub fn tai_offset(&self, dt: &crate::Utc) -> i32 {
+        // A leap second counts as the second before it so it falls before
+        // the change at midnight.
+        let seconds = dt.days_since_epoch() * 86_400 +
+            (dt.hour() * 3_600 + dt.minute() * 60 + dt.second().min(59)) as i64;
+        let ntp = seconds + NTP_UNIX_OFFSET as i64;
+
+        let entries = self.entries();
+        entries.iter()
+            .rev()
+            .find(|(start, _)| *start as i64 <= ntp)
+            .or(entries.first())
+            .map(|(_, offset)| *offset)
+            .unwrap_or(0)
+    }
+
+    /// Gets each change in TAI − UTC after the first as nanoseconds since
+    /// the unix epoch when it happened, with the offsets before and after in
+    /// nanoseconds.
+    pub(crate) fn steps(&self) -> impl Iterator<Item = (i128, i128, i128)> + '_ {
+        self.entries().windows(2).map(|pair| (
+            (pair[1].0 as i128 - NTP_UNIX_OFFSET as i128) * 1_000_000_000,
+            pair[0].1 as i128 * 1_000_000_000,
+            pair[1].1 as i128 * 1_000_000_000,
+        ))
+    }
+
+    /// Gets the number of nanoseconds since 1970-01-01T00:00:00 TAI.
+    pub(crate) fn tai_nanos(&self, dt: &crate::Utc) -> i128 {
+        dt.nanos_since_epoch() + self.tai_offset(dt) as i128 * 1_000_000_000
+    }
+
+    /// Gets the date/time a number of nanoseconds after 1970-01-01T00:00:00
+    /// TAI, or `None` if it is out of range.
+    pub(crate) fn utc_from_tai_nanos(&self, tai: i128) -> Option<crate::Utc> {
+        let entries = self.entries();
+        for (i, (ntp, offset)) in entries.iter().enumerate().rev() {
+            let start = (*ntp as i128 - NTP_UNIX_OFFSET as i128) * 1_000_000_000;
+            let offset = *offset as i128 * 1_000_000_000;
+            if tai >= start + offset {
+                return crate::Utc::from_nanos_since_epoch(tai - offset);
+            }
+
+            // The seconds TAI gains at a leap second are spent at 23:59:60.
+            let previous = i.checked_sub(1).map(|i| entries[i].1 as i128 * 1_000_000_000);
+            if let Some(previous) = previous.filter(|previous| *previous < offset) {
+                if tai >= start + previous {
+                    let before = crate::Utc::from_nanos_since_epoch(start - 1_000_000_000)?;
+                    let into = (tai - start - previous) as f64 / 1_000_000_000_f64;
+                    return Some(before.leap_second_after(into));
+                }
+            }
+        }
+
+        let first = entries.first().map_or(0, |(_, offset)| *offset as i128);
+        crate::Utc::from_nanos_since_epoch(tai - first * 1_000_000_000)
+    }
+
+    /// Gets the number of leap seconds from one date/time up to another,
+    /// negative if `b` is before `a`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, Utc, };
+    ///
+    /// let table = LeapSeconds::embedded();
+    /// let a = Utc::from_ymd(2016, 1, 1);
+    /// let b = Utc::from_ymd(2017, 1, 1);
+    /// assert_eq!(1, table.leap_seconds_between(&a, &b));
+    /// assert_eq!(-1, table.leap_seconds_between(&b, &a));
+    /// ```
+    pub fn leap_seconds_between(&self, a: &crate::Utc, b: &crate::Utc) -> i64 {
+        self.tai_offset(b) as i64 - self.tai_offset(a) as i64
+    }
+}
+
+impl Default for LeapSeconds {
+    fn default() -> Self {
+        Self::embedded()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::str::FromStr for LeapSeconds {
+    type Err = LeapSecondsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// An error encountered while loading a [`LeapSeconds`] table.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub enum LeapSecondsError {
+    /// The file could not be read.
+    #[cfg(feature = "std")]
+    Io(std::io::Error),
+    /// The line with the given number is not a valid entry.
+    InvalidLine(usize),
+    /// The entry on the line with the given number is not after the one
+    /// before it.
+    NotIncreasing(usize),
+    /// The table has no entries.
+    Empty,
+}
+
+#[cfg(feature = "alloc")]
+impl core::fmt::Display for LeapSecondsError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(error) => write!(fmtr, "could not read leap seconds: {error}"),
+            Self::InvalidLine(line) => write!(fmtr, "invalid leap second entry on line {line}"),
+            Self::NotIncreasing(line) => write!(fmtr, "leap second entry on line {line} is out of order"),
+            Self::Empty => fmtr.write_str("no leap second entries"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl core::error::Error for LeapSecondsError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "std")]
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
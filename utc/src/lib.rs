@@ -1,11 +1,97 @@
-//! Computes UTC datetimes after 1970-01-01T00:00:00 using only the
+//! Computes UTC datetimes from 0001-01-01 to 9999-12-31 using only the
 //! [time](`std::time`) module.
+//!
+//! # Features
+//!
+//! - `std` (default): [`Utc::now`], [`std::time::SystemTime`] conversions,
+//!   the `SystemClock`, `MockClock`, `MonotonicUtc`, `HybridLogicalClock`
+//!   and `CachedClock`, the `SntpClient` and the `write_*_io` formatters.
+//!   Implies `alloc`.
+//! - `alloc`: the `as_*` formatters returning a `String`, parsing,
+//!   relative/natural language dates and the ZIP extended timestamp
+//!   encoders.
+//! - `coarse`: lets a `CachedClock` read `CLOCK_REALTIME_COARSE` on Linux.
+//!   Implies `std`.
+//!
+//! Without either the crate is `#![no_std]`, keeping the calendar math,
+//! [`Utc`] constructors, [`Utc::from_epoch_seconds`] and friends for
+//! feeding in an RTC reading, and the allocation-free [`FormatBuf`]
+//! formatters.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[cfg(test)]
 mod test;
 
+pub mod calendar;
+
+#[cfg(feature = "std")]
+mod cached;
+mod clock;
+mod dos;
+mod duration;
+#[cfg(feature = "alloc")]
+mod extract;
+mod format;
+mod hlc;
+mod leap;
+#[cfg(feature = "alloc")]
+mod natural;
+mod ntp;
+#[cfg(feature = "alloc")]
+mod parse;
+mod range;
+#[cfg(feature = "alloc")]
+mod relative;
+mod serial;
+mod smear;
+#[cfg(feature = "std")]
+mod sntp;
 mod statics;
+mod tai64;
+mod timescale;
 mod utc;
+mod windows;
+mod zip;
+
+pub use crate::{
+    calendar::Weekday,
+    clock::{ Clock, FixedClock, OffsetClock, },
+    dos::{ DosDateTime, DosRounding, },
+    duration::{ DurationStyle, HumanDuration, },
+    format::FormatBuf,
+    hlc::{ HlcError, HlcTimestamp, ParseHlcError, },
+    leap::LeapSeconds,
+    ntp::{ NtpShort, NtpTimestamp, },
+    range::{ Component, RangeError, },
+    serial::{ ExcelDateSystem, OleDate, },
+    smear::{ LeapSmear, SmearShape, SmearedClock, },
+    tai64::{ ParseTai64Error, Tai64, Tai64N, Tai64NA, },
+    timescale::{ Gps, Tai, },
+    utc::Utc,
+    windows::{ DateTimeKind, DotNetDateTime, FileTime, Win32SystemTime, },
+    zip::{ ExtendedTimestamp, NtfsTimes, },
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::{
+    duration::{ parse_duration, ParseDurationError, },
+    extract::{ find_timestamps, normalize_timestamps, FoundTimestamp, },
+    leap::LeapSecondsError,
+    natural::{ parse_natural_at, NaturalDate, ParseNaturalError, },
+    parse::{ DateOrder, EpochUnit, Format, ParseAnyError, ParseHints, },
+    relative::RelativeFormat,
+};
 
-pub use crate::utc::Utc;
+#[cfg(feature = "std")]
+pub use crate::{
+    cached::CachedClock,
+    clock::{ MockClock, MonotonicUtc, SystemClock, },
+    hlc::HybridLogicalClock,
+    natural::parse_natural,
+    sntp::{ SntpClient, SntpError, SntpResponse, },
+};
 
@@ -0,0 +1,528 @@
+/// The result of resolving a natural-language date expression.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NaturalDate {
+    /// A single point in time, e.g. `tomorrow 9am` or `3 days ago`.
+    Instant(crate::Utc),
+    /// A half-open span of time, e.g. `next month`.
+    Interval(crate::Utc, crate::Utc),
+}
+
+impl NaturalDate {
+    /// Gets the instant, or the start of the interval.
+    pub fn start(&self) -> crate::Utc {
+        match self {
+            Self::Instant(dt) => *dt,
+            Self::Interval(start, _) => *start,
+        }
+    }
+}
+
+/// An error encountered while parsing a natural-language date expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseNaturalError {
+    /// The token at the given byte offset could not be parsed.
+    UnexpectedToken {
+        /// The byte offset of the token within the input.
+        position: usize,
+        /// The token which failed to parse.
+        token: alloc::string::String,
+        /// A description of what was expected instead.
+        expected: &'static str,
+    },
+    /// The input ended early.
+    UnexpectedEnd {
+        /// A description of what was expected.
+        expected: &'static str,
+    },
+    /// The expression resolved to a date/time which cannot be represented.
+    OutOfRange,
+}
+
+impl core::fmt::Display for ParseNaturalError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedToken { position, token, expected, } => write!(
+                fmtr, "unexpected \"{token}\" at byte {position}, expected {expected}"
+            ),
+            Self::UnexpectedEnd { expected, } => write!(
+                fmtr, "unexpected end of input, expected {expected}"
+            ),
+            Self::OutOfRange => fmtr.write_str("date is out of range"),
+        }
+    }
+}
+
+impl core::error::Error for ParseNaturalError {}
+
+/// Parses a natural-language date expression relative to the current
+/// date/time.
+///
+/// See [`parse_natural_at`] for the accepted expressions.
+#[cfg(feature = "std")]
+pub fn parse_natural(input: &str) -> Result<NaturalDate, ParseNaturalError> {
+    parse_natural_at(input, &crate::Utc::now())
+}
+
+/// Parses a natural-language date expression relative to `reference`.
+///
+/// Accepted expressions are case-insensitive and include:
+///
+/// - `now`, `today`, `tomorrow`, `yesterday`
+/// - weekdays: `friday`, `this friday`, `next friday`, `last friday`
+/// - periods: `this week`, `next month`, `last year`, resolved to an
+///   [`NaturalDate::Interval`] with weeks starting on Monday
+/// - `first day of next month`, `last day of this year`
+/// - offsets: `3 days ago`, `2 weeks from now`, `in an hour`
+/// - a time of day such as `9am`, `9:30 pm`, `21:00`, `noon` or `midnight`,
+///   on its own or before or after (optionally with `at`) any of the
+///   day-level expressions above
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ parse_natural_at, NaturalDate, Utc, };
+///
+/// let now = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64); // a Tuesday
+///
+/// assert_eq!(
+///     NaturalDate::Instant(Utc::from_ymdhms(2024, 1, 10, 9, 0, 0_f64)),
+///     parse_natural_at("tomorrow 9am", &now).unwrap()
+/// );
+/// assert_eq!(
+///     NaturalDate::Instant(Utc::from_ymd(2024, 1, 12)),
+///     parse_natural_at("next friday", &now).unwrap()
+/// );
+/// assert_eq!(
+///     NaturalDate::Instant(Utc::from_ymd(2024, 2, 29)),
+///     parse_natural_at("last day of next month", &now).unwrap()
+/// );
+/// assert_eq!(
+///     NaturalDate::Instant(Utc::from_ymdhms(2024, 1, 23, 16, 21, 0_f64)),
+///     parse_natural_at("2 weeks from now", &now).unwrap()
+/// );
+///
+/// let err = parse_natural_at("3 fortnights ago", &now).unwrap_err();
+/// assert_eq!(
+///     "unexpected \"fortnights\" at byte 2, expected a unit of time",
+///     err.to_string()
+/// );
+/// ```
+pub fn parse_natural_at(
+    input: &str, reference: &crate::Utc,
+) -> Result<NaturalDate, ParseNaturalError> {
+    let mut parser = Parser {
+        tokens: tokenize(input),
+        index: 0,
+        reference,
+    };
+
+    let date = parser.expression()?;
+    if let Some((position, token)) = parser.tokens.get(parser.index) {
+        return Err(ParseNaturalError::UnexpectedToken {
+            position: *position,
+            token: token.clone(),
+            expected: "end of input",
+        });
+    }
+
+    Ok(date)
+}
+
+#[derive(Clone, Copy)]
+enum Unit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Unit {
+    fn parse(token: &str) -> Option<Self> {
+        Some(match token {
+            "s" | "sec" | "secs" | "second" | "seconds" => Self::Second,
+            "min" | "mins" | "minute" | "minutes" => Self::Minute,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Self::Hour,
+            "d" | "day" | "days" => Self::Day,
+            "w" | "wk" | "wks" | "week" | "weeks" => Self::Week,
+            "mo" | "month" | "months" => Self::Month,
+            "y" | "yr" | "yrs" | "year" | "years" => Self::Year,
+            _ => return None,
+        })
+    }
+
+    fn seconds(self) -> Option<i128> {
+        match self {
+            Self::Second => Some(1),
+            Self::Minute => Some(60),
+            Self::Hour => Some(3_600),
+            Self::Day => Some(86_400),
+            Self::Week => Some(604_800),
+            Self::Month | Self::Year => None,
+        }
+    }
+}
+
+fn tokenize(input: &str) -> alloc::vec::Vec<(usize, alloc::string::String)> {
+    let mut tokens = alloc::vec::Vec::new();
+    let mut start = None;
+    for (i, c) in input.char_indices().chain(core::iter::once((input.len(), ' '))) {
+        let separator = c.is_whitespace() || c == ',';
+        match (start, separator) {
+            (None, false) => start = Some(i),
+            (Some(s), true) => {
+                tokens.push((s, input[s..i].to_lowercase()));
+                start = None;
+            },
+            _ => {},
+        }
+    }
+
+    tokens
+}
+
+/// Gets the index of the weekday in [`crate::statics::D`].
+fn weekday(token: &str) -> Option<usize> {
+    super::statics::D.iter().position(|name| {
+        let name = name.to_lowercase();
+        token == name || token == &name[0..3]
+    })
+}
+
+fn number(token: &str) -> Option<i128> {
+    const WORDS: [&str; 13] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight",
+        "nine", "ten", "eleven", "twelve",
+    ];
+
+    match token {
+        "a" | "an" => Some(1),
+        _ if token.bytes().all(|b| b.is_ascii_digit()) => token.parse().ok(),
+        _ => WORDS.iter().position(|w| *w == token).map(|n| n as i128),
+    }
+}
+
+fn midnight(dt: &crate::Utc) -> crate::Utc {
+    crate::Utc::from_ymd(dt.year(), dt.month(), dt.day())
+}
+
+fn at_time(dt: &crate::Utc, (hour, minute, second): (usize, usize, usize)) -> crate::Utc {
+    crate::Utc::from_ymdhms(dt.year(), dt.month(), dt.day(), hour, minute, second as f64)
+}
+
+fn add_seconds(dt: &crate::Utc, seconds: i128) -> Result<crate::Utc, ParseNaturalError> {
+    seconds.checked_mul(1_000_000_000)
+        .and_then(|nanos| dt.nanos_since_epoch().checked_add(nanos))
+        .and_then(crate::Utc::from_nanos_since_epoch)
+        .ok_or(ParseNaturalError::OutOfRange)
+}
+
+/// Adds calendar months, clamping the day to the end of the resulting month.
+fn add_months(dt: &crate::Utc, months: i128) -> Result<crate::Utc, ParseNaturalError> {
+    let total = months.checked_add((dt.year() * super::statics::MIY + dt.month() - 1) as i128)
+        .ok_or(ParseNaturalError::OutOfRange)?;
+    if total < (super::statics::MIN_Y * super::statics::MIY) as i128 ||
+        total >= ((super::statics::MAX_Y + 1) * super::statics::MIY) as i128
+    {
+        return Err(ParseNaturalError::OutOfRange);
+    }
+
+    let year = total as usize / super::statics::MIY;
+    let month = total as usize % super::statics::MIY + 1;
+    let day = dt.day().min(super::statics::DIM[month - 1][super::statics::ly(year)]);
+    // The time of day is added on so that a leap second carrying past
+    // 9999-12-31 is an error rather than a panic.
+    let time = (dt.hour() * 3_600 + dt.minute() * 60 + dt.second()) as i128 * 1_000_000_000 +
+        dt.nanosecond() as i128;
+    let date = crate::Utc::from_ymd(year, month, day);
+    crate::Utc::from_nanos_since_epoch(date.nanos_since_epoch() + time)
+        .ok_or(ParseNaturalError::OutOfRange)
+}
+
+fn shift(dt: &crate::Utc, amount: i128, unit: Unit) -> Result<crate::Utc, ParseNaturalError> {
+    match (unit.seconds(), unit) {
+        (Some(seconds), _) => add_seconds(
+            dt, amount.checked_mul(seconds).ok_or(ParseNaturalError::OutOfRange)?,
+        ),
+        (None, Unit::Month) => add_months(dt, amount),
+        (None, _) => add_months(
+            dt, amount.checked_mul(12).ok_or(ParseNaturalError::OutOfRange)?,
+        ),
+    }
+}
+
+struct Parser<'a> {
+    tokens: alloc::vec::Vec<(usize, alloc::string::String)>,
+    index: usize,
+    reference: &'a crate::Utc,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.peek_at(0)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&str> {
+        self.tokens.get(self.index + offset).map(|(_, t)| t.as_str())
+    }
+
+    fn error(&self, expected: &'static str) -> ParseNaturalError {
+        match self.tokens.get(self.index) {
+            Some((position, token)) => ParseNaturalError::UnexpectedToken {
+                position: *position,
+                token: token.clone(),
+                expected,
+            },
+            None => ParseNaturalError::UnexpectedEnd { expected, },
+        }
+    }
+
+    fn expect(&mut self, token: &'static str, expected: &'static str) -> Result<(), ParseNaturalError> {
+        if self.peek() != Some(token) {
+            return Err(self.error(expected));
+        }
+
+        self.index += 1;
+        Ok(())
+    }
+
+    fn expression(&mut self) -> Result<NaturalDate, ParseNaturalError> {
+        if let Some(time) = self.time()? {
+            let day = match self.peek() {
+                Some(_) => self.day()?,
+                None => midnight(self.reference),
+            };
+
+            return Ok(NaturalDate::Instant(at_time(&day, time)));
+        }
+
+        let Some(token) = self.peek() else {
+            return Err(self.error("a date expression"));
+        };
+
+        match token {
+            "now" => {
+                self.index += 1;
+                Ok(NaturalDate::Instant(*self.reference))
+            },
+            "in" => {
+                self.index += 1;
+                let (amount, unit) = self.amount()?;
+                Ok(NaturalDate::Instant(shift(self.reference, amount, unit)?))
+            },
+            "next" | "last" | "this" if self.peek_at(1).and_then(period).is_some() => {
+                let offset = self.relative()?;
+                let unit = period(self.peek().unwrap()).unwrap();
+                self.index += 1;
+                let (start, end) = self.period(unit, offset)?;
+                Ok(NaturalDate::Interval(start, end))
+            },
+            _ if number(token).is_some() => {
+                let (amount, unit) = self.amount()?;
+                let amount = match self.peek() {
+                    Some("ago") => {
+                        self.index += 1;
+                        -amount
+                    },
+                    Some("from") => {
+                        self.index += 1;
+                        self.expect("now", "\"now\"")?;
+                        amount
+                    },
+                    _ => return Err(self.error("\"ago\" or \"from now\"")),
+                };
+                Ok(NaturalDate::Instant(shift(self.reference, amount, unit)?))
+            },
+            _ => {
+                let day = self.day()?;
+                match self.peek() {
+                    Some("at") => {
+                        self.index += 1;
+                        match self.time()? {
+                            Some(time) => Ok(NaturalDate::Instant(at_time(&day, time))),
+                            None => Err(self.error("a time of day")),
+                        }
+                    },
+                    Some(_) => match self.time()? {
+                        Some(time) => Ok(NaturalDate::Instant(at_time(&day, time))),
+                        None => Err(self.error("a time of day or end of input")),
+                    },
+                    None => Ok(NaturalDate::Instant(day)),
+                }
+            },
+        }
+    }
+
+    /// Parses an expression resolving to the start of a day.
+    fn day(&mut self) -> Result<crate::Utc, ParseNaturalError> {
+        let today = midnight(self.reference);
+        let Some(token) = self.peek() else {
+            return Err(self.error("a day"));
+        };
+
+        match token {
+            "today" => {
+                self.index += 1;
+                Ok(today)
+            },
+            "tomorrow" => {
+                self.index += 1;
+                add_seconds(&today, 86_400)
+            },
+            "yesterday" => {
+                self.index += 1;
+                add_seconds(&today, -86_400)
+            },
+            "first" | "last" if self.peek_at(1) == Some("day") => {
+                let first = token == "first";
+                self.index += 2;
+                self.expect("of", "\"of\"")?;
+                let offset = self.relative()?;
+                let Some(unit) = self.peek().and_then(period) else {
+                    return Err(self.error("week, month or year"));
+                };
+                self.index += 1;
+                let (start, end) = self.period(unit, offset)?;
+                if first {
+                    Ok(start)
+                } else {
+                    add_seconds(&end, -86_400)
+                }
+            },
+            _ => {
+                let offset = match token {
+                    "next" | "last" | "this" => Some(self.relative()?),
+                    _ => None,
+                };
+                let Some(target) = self.peek().and_then(weekday) else {
+                    return Err(self.error("a day"));
+                };
+                self.index += 1;
+
+                let current = today.days_since_epoch()
+                    .rem_euclid(super::statics::DIW as i64) as usize;
+                let ahead = (target + super::statics::DIW - current) % super::statics::DIW;
+                let days = match offset {
+                    Some(1) if ahead == 0 => super::statics::DIW as i128,
+                    Some(-1) if ahead == 0 => -(super::statics::DIW as i128),
+                    Some(-1) => ahead as i128 - super::statics::DIW as i128,
+                    _ => ahead as i128,
+                };
+                add_seconds(&today, days * 86_400)
+            },
+        }
+    }
+
+    /// Parses `next`, `last` or `this` as an offset of -1, 0 or 1.
+    fn relative(&mut self) -> Result<i128, ParseNaturalError> {
+        let offset = match self.peek() {
+            Some("next") => 1,
+            Some("last") => -1,
+            Some("this") => 0,
+            _ => return Err(self.error("\"next\", \"last\" or \"this\"")),
+        };
+        self.index += 1;
+        Ok(offset)
+    }
+
+    /// Gets the start and end of the week, month or year `offset` periods
+    /// away from the one containing the reference date/time.
+    fn period(&self, unit: Unit, offset: i128) -> Result<(crate::Utc, crate::Utc), ParseNaturalError> {
+        let today = midnight(self.reference);
+        match unit {
+            Unit::Week => {
+                // Days since Monday, as the epoch was a Thursday.
+                let weekday = (today.days_since_epoch() + 3).rem_euclid(super::statics::DIW as i64);
+                let start = add_seconds(&today, (offset * 7 - weekday as i128) * 86_400)?;
+                Ok((start, add_seconds(&start, 7 * 86_400)?))
+            },
+            Unit::Month => {
+                let first = crate::Utc::from_ymd(today.year(), today.month(), 1);
+                let start = add_months(&first, offset)?;
+                Ok((start, add_months(&start, 1)?))
+            },
+            _ => {
+                let first = crate::Utc::from_ymd(today.year(), 1, 1);
+                let start = add_months(&first, offset * 12)?;
+                Ok((start, add_months(&start, 12)?))
+            },
+        }
+    }
+
+    fn amount(&mut self) -> Result<(i128, Unit), ParseNaturalError> {
+        let Some(amount) = self.peek().and_then(number) else {
+            return Err(self.error("a number"));
+        };
+        self.index += 1;
+
+        let Some(unit) = self.peek().and_then(Unit::parse) else {
+            return Err(self.error("a unit of time"));
+        };
+        self.index += 1;
+
+        Ok((amount, unit))
+    }
+
+    /// Parses a time of day if the next token looks like one.
+    fn time(&mut self) -> Result<Option<(usize, usize, usize)>, ParseNaturalError> {
+        let Some(token) = self.peek() else {
+            return Ok(None);
+        };
+
+        match token {
+            "noon" => {
+                self.index += 1;
+                return Ok(Some((12, 0, 0)));
+            },
+            "midnight" => {
+                self.index += 1;
+                return Ok(Some((0, 0, 0)));
+            },
+            _ => {},
+        }
+
+        let (body, meridiem, consumed) = if let Some(body) = token.strip_suffix("am") {
+            (body, Some(0), 1)
+        } else if let Some(body) = token.strip_suffix("pm") {
+            (body, Some(12), 1)
+        } else if self.peek_at(1) == Some("am") {
+            (token, Some(0), 2)
+        } else if self.peek_at(1) == Some("pm") {
+            (token, Some(12), 2)
+        } else if token.contains(':') {
+            (token, None, 1)
+        } else {
+            return Ok(None);
+        };
+
+        let mut parts = body.split(':').map(|p| {
+            (!p.is_empty() && p.len() <= 2 && p.bytes().all(|b| b.is_ascii_digit()))
+                .then(|| p.parse::<usize>().ok())
+                .flatten()
+        });
+        let hour = parts.next().flatten();
+        let minute = parts.next().unwrap_or(Some(0));
+        let second = parts.next().unwrap_or(Some(0));
+        let time = match (hour, minute, second, parts.next(), meridiem) {
+            (Some(h), Some(m), Some(s), None, Some(offset))
+                if (1..=12).contains(&h) && m < 60 && s < 60 => (h % 12 + offset, m, s),
+            (Some(h), Some(m), Some(s), None, None)
+                if h < 24 && m < 60 && s < 60 => (h, m, s),
+            _ => return Err(self.error("a time of day")),
+        };
+
+        self.index += consumed;
+        Ok(Some(time))
+    }
+}
+
+fn period(token: &str) -> Option<Unit> {
+    match token {
+        "week" => Some(Unit::Week),
+        "month" => Some(Unit::Month),
+        "year" => Some(Unit::Year),
+        _ => None,
+    }
+}
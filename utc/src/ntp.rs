@@ -0,0 +1,242 @@
+/// Seconds in an NTP era, after which the 32 bit seconds wrap.
+const ERA_SECONDS: i128 = 1 << 32;
+
+/// Gets the nearest number of nanoseconds to a binary fraction of a second
+/// with the given number of bits, which may be a whole second.
+const fn fraction_to_nanos(fraction: u64, bits: u32) -> u64 {
+    (fraction * 1_000_000_000 + (1 << (bits - 1))) >> bits
+}
+
+/// Gets the nearest binary fraction of a second with the given number of
+/// bits to a number of nanoseconds, which may be a whole second.
+const fn nanos_to_fraction(nanos: u64, bits: u32) -> u64 {
+    ((nanos << bits) + 500_000_000) / 1_000_000_000
+}
+
+/// An NTP timestamp in the 64 bit format: 32 bits of seconds since the
+/// start of an era and 32 bits of fraction of a second.
+///
+/// Era 0 started at the NTP epoch, 1900-01-01T00:00:00, and ends at
+/// 2036-02-07T06:28:16, when era 1 starts. As the timestamp does not say
+/// which era it is in, it is taken to be the era nearest a reference
+/// date/time, or as in RFC 4330, from 1968 up to 2104.
+///
+/// Fractions are rounded to the nearest nanosecond, with halves rounded up,
+/// and nanoseconds to the nearest fraction, so that any number of
+/// nanoseconds round trips.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ NtpTimestamp, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let ntp = NtpTimestamp::from_utc(&dt);
+/// assert_eq!(0xe947_f0ec, ntp.seconds());
+/// assert_eq!(0x8000_0000, ntp.fraction());
+/// assert_eq!("e947f0ec.80000000", ntp.to_string());
+/// assert_eq!(Some(dt), ntp.to_utc());
+/// assert_eq!(ntp, NtpTimestamp::from_bytes(ntp.to_bytes()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTimestamp {
+    seconds: u32,
+    fraction: u32,
+}
+
+impl NtpTimestamp {
+    /// Gets a timestamp from its seconds into the era and fraction of a
+    /// second in units of 2⁻³² s.
+    pub const fn new(seconds: u32, fraction: u32) -> Self {
+        Self { seconds, fraction, }
+    }
+
+    /// Gets the number of seconds into the era.
+    pub const fn seconds(&self) -> u32 {
+        self.seconds
+    }
+
+    /// Gets the fraction of a second in units of 2⁻³² s.
+    pub const fn fraction(&self) -> u32 {
+        self.fraction
+    }
+
+    /// Gets the timestamp of a date/time, dropping the era.
+    ///
+    /// A leap second, 23:59:60, gets the timestamp of the midnight after it.
+    pub const fn from_utc(dt: &crate::Utc) -> Self {
+        let nanos = dt.nanos_since_epoch() + crate::leap::NTP_UNIX_OFFSET as i128 * 1_000_000_000;
+        let fraction = nanos_to_fraction(nanos.rem_euclid(1_000_000_000) as u64, 32);
+        let seconds = nanos.div_euclid(1_000_000_000) + (fraction >> 32) as i128;
+        Self { seconds: seconds as u32, fraction: fraction as u32, }
+    }
+
+    /// Gets the NTP era a date/time is in.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ NtpTimestamp, Utc, };
+    ///
+    /// assert_eq!(0, NtpTimestamp::era_of(&Utc::from_ymd(2036, 2, 7)));
+    /// assert_eq!(1, NtpTimestamp::era_of(&Utc::from_ymdhms(2036, 2, 7, 6, 28, 16_f64)));
+    /// ```
+    pub const fn era_of(dt: &crate::Utc) -> i32 {
+        let seconds = dt.nanos_since_epoch().div_euclid(1_000_000_000) +
+            crate::leap::NTP_UNIX_OFFSET as i128;
+        seconds.div_euclid(ERA_SECONDS) as i32
+    }
+
+    /// Gets the date/time in an era, or `None` if it is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ NtpTimestamp, Utc, };
+    ///
+    /// let ntp = NtpTimestamp::new(0, 0);
+    /// assert_eq!(Some(Utc::from_ymd(1900, 1, 1)), ntp.to_utc_in_era(0));
+    /// assert_eq!(Some(Utc::from_ymdhms(2036, 2, 7, 6, 28, 16_f64)), ntp.to_utc_in_era(1));
+    /// ```
+    pub const fn to_utc_in_era(&self, era: i32) -> Option<crate::Utc> {
+        let seconds = era as i128 * ERA_SECONDS + self.seconds as i128 -
+            crate::leap::NTP_UNIX_OFFSET as i128;
+        let nanos = fraction_to_nanos(self.fraction as u64, 32) as i128;
+        crate::Utc::from_nanos_since_epoch(seconds * 1_000_000_000 + nanos)
+    }
+
+    /// Gets the date/time between 1968-01-20T03:14:08 and
+    /// 2104-02-26T09:42:24, using era 0 if the top bit of the seconds is set
+    /// and era 1 if not, as in RFC 4330.
+    pub const fn to_utc(&self) -> Option<crate::Utc> {
+        self.to_utc_in_era(if self.seconds >> 31 == 1 { 0 } else { 1 })
+    }
+
+    /// Gets the date/time in the era which puts it nearest a reference
+    /// date/time, e.g. the local clock, or `None` if it is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ NtpTimestamp, Utc, };
+    ///
+    /// let ntp = NtpTimestamp::from_utc(&Utc::from_ymd(2150, 1, 1));
+    /// assert_eq!(Some(Utc::from_ymd(2150, 1, 1)), ntp.to_utc_near(&Utc::from_ymd(2140, 1, 1)));
+    /// assert_ne!(Some(Utc::from_ymd(2150, 1, 1)), ntp.to_utc());
+    /// ```
+    pub const fn to_utc_near(&self, reference: &crate::Utc) -> Option<crate::Utc> {
+        let reference = reference.nanos_since_epoch().div_euclid(1_000_000_000) +
+            crate::leap::NTP_UNIX_OFFSET as i128;
+        let earliest = reference - ERA_SECONDS / 2;
+        let seconds = earliest + (self.seconds as i128 - earliest).rem_euclid(ERA_SECONDS);
+        self.to_utc_in_era(seconds.div_euclid(ERA_SECONDS) as i32)
+    }
+
+    /// Encodes the timestamp as a 64 bit number, seconds in the upper half.
+    pub const fn to_u64(&self) -> u64 {
+        (self.seconds as u64) << 32 | self.fraction as u64
+    }
+
+    /// Decodes a timestamp from a 64 bit number, seconds in the upper half.
+    pub const fn from_u64(timestamp: u64) -> Self {
+        Self { seconds: (timestamp >> 32) as u32, fraction: timestamp as u32, }
+    }
+
+    /// Encodes the timestamp as it is sent in a packet.
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.to_u64().to_be_bytes()
+    }
+
+    /// Decodes a timestamp as it is sent in a packet.
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_u64(u64::from_be_bytes(bytes))
+    }
+}
+
+/// Formats as the seconds and fraction in hex separated by a `.`, as
+/// `ntpq` does.
+impl core::fmt::Display for NtpTimestamp {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(fmtr, "{:08x}.{:08x}", self.seconds, self.fraction)
+    }
+}
+
+/// An NTP short format value: 16 bits of seconds and 16 bits of fraction of
+/// a second, used for durations such as the root delay and dispersion.
+///
+/// Fractions are rounded to the nearest nanosecond and nanoseconds to the
+/// nearest fraction, with halves rounded up.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::NtpShort;
+///
+/// let delay = NtpShort::from_u32(0x0001_8000);
+/// assert_eq!(Duration::from_millis(1_500), delay.to_duration());
+/// assert_eq!(Some(delay), NtpShort::from_duration(Duration::from_millis(1_500)));
+/// assert_eq!(None, NtpShort::from_duration(Duration::from_secs(65_536)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpShort {
+    seconds: u16,
+    fraction: u16,
+}
+
+impl NtpShort {
+    /// Gets a value from its seconds and fraction of a second in units of
+    /// 2⁻¹⁶ s.
+    pub const fn new(seconds: u16, fraction: u16) -> Self {
+        Self { seconds, fraction, }
+    }
+
+    /// Gets the number of whole seconds.
+    pub const fn seconds(&self) -> u16 {
+        self.seconds
+    }
+
+    /// Gets the fraction of a second in units of 2⁻¹⁶ s.
+    pub const fn fraction(&self) -> u16 {
+        self.fraction
+    }
+
+    /// Gets the nearest value to a duration, or `None` if it is 65536
+    /// seconds or more once rounded.
+    pub const fn from_duration(duration: core::time::Duration) -> Option<Self> {
+        let fraction = nanos_to_fraction(duration.subsec_nanos() as u64, 16);
+        let seconds = duration.as_secs() + (fraction >> 16);
+        if seconds > u16::MAX as u64 {
+            return None;
+        }
+
+        Some(Self { seconds: seconds as u16, fraction: fraction as u16, })
+    }
+
+    /// Gets the duration to the nearest nanosecond.
+    pub const fn to_duration(&self) -> core::time::Duration {
+        let nanos = fraction_to_nanos(self.fraction as u64, 16);
+        core::time::Duration::new(self.seconds as u64, 0)
+            .saturating_add(core::time::Duration::from_nanos(nanos))
+    }
+
+    /// Encodes the value as a 32 bit number, seconds in the upper half.
+    pub const fn to_u32(&self) -> u32 {
+        (self.seconds as u32) << 16 | self.fraction as u32
+    }
+
+    /// Decodes a value from a 32 bit number, seconds in the upper half.
+    pub const fn from_u32(value: u32) -> Self {
+        Self { seconds: (value >> 16) as u16, fraction: value as u16, }
+    }
+
+    /// Encodes the value as it is sent in a packet.
+    pub const fn to_bytes(&self) -> [u8; 4] {
+        self.to_u32().to_be_bytes()
+    }
+
+    /// Decodes a value as it is sent in a packet.
+    pub const fn from_bytes(bytes: [u8; 4]) -> Self {
+        Self::from_u32(u32::from_be_bytes(bytes))
+    }
+}
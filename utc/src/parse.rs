@@ -0,0 +1,656 @@
+/// A timestamp format recognized by [`Utc::parse_any`](crate::Utc::parse_any).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// `2024-01-09T16:21:00.5Z`, with an optional fraction and an optional
+    /// `Z` or `±HH:MM` offset.
+    Rfc3339,
+    /// `2024-01-09 16:21:00.500` as produced by
+    /// [`Utc::as_iso8601`](crate::Utc::as_iso8601), or a bare `2024-01-09`.
+    Iso8601,
+    /// `Tue, 09 Jan 2024 16:21:00 GMT`.
+    Rfc7231,
+    /// Seconds or milliseconds since the unix epoch, e.g. `1704817260`.
+    Epoch,
+    /// Numeric dates with the year last, e.g. `01/09/2024` or `09.01.2024`,
+    /// optionally followed by a `HH:MM[:SS]` time.
+    NumericDate,
+    /// Dates with a month name, e.g. `9 Jan 2024` or `January 9, 2024`,
+    /// optionally followed by a `HH:MM[:SS]` time.
+    NamedMonth,
+    /// BSD syslog timestamps without a year, e.g. `Jan  9 16:21:00`.
+    Syslog,
+    /// Apache common log format, e.g. `09/Jan/2024:16:21:00 +0000`.
+    CommonLog,
+}
+
+/// The default order in which formats are tried.
+const FORMATS: [Format; 8] = [
+    Format::Rfc3339,
+    Format::Iso8601,
+    Format::Rfc7231,
+    Format::Epoch,
+    Format::NumericDate,
+    Format::NamedMonth,
+    Format::Syslog,
+    Format::CommonLog,
+];
+
+/// How to read the first two fields of a [`Format::NumericDate`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateOrder {
+    /// Report [`ParseAnyError::Ambiguous`] when both readings are valid and
+    /// differ.
+    #[default]
+    Unknown,
+    /// Read `03/04/2024` as the 3rd of April.
+    DayFirst,
+    /// Read `03/04/2024` as March 4th.
+    MonthFirst,
+}
+
+/// The unit of a [`Format::Epoch`] timestamp.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EpochUnit {
+    /// Milliseconds when the value is at least 100,000,000,000 (a date in
+    /// 1973 in milliseconds, or in 5138 in seconds), otherwise seconds.
+    #[default]
+    Auto,
+    /// Always seconds.
+    Seconds,
+    /// Always milliseconds.
+    Millis,
+}
+
+/// Hints for [`Utc::parse_any`](crate::Utc::parse_any).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseHints {
+    formats: alloc::vec::Vec<Format>,
+    date_order: DateOrder,
+    epoch_unit: EpochUnit,
+    year: Option<usize>,
+    leap_seconds: crate::LeapSeconds,
+}
+
+impl Default for ParseHints {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParseHints {
+    /// Gets hints which try every format, report ambiguous numeric dates,
+    /// detect the epoch unit and assume the current year where one is
+    /// missing.
+    pub fn new() -> Self {
+        Self {
+            formats: FORMATS.to_vec(),
+            date_order: DateOrder::Unknown,
+            epoch_unit: EpochUnit::Auto,
+            year: None,
+            leap_seconds: crate::LeapSeconds::embedded(),
+        }
+    }
+
+    /// Sets the formats to try, in order of priority.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ Format, ParseHints, Utc, };
+    ///
+    /// let hints = ParseHints::new().formats(&[Format::Rfc7231]);
+    /// assert!(Utc::parse_any("Tue, 09 Jan 2024 16:21:00 GMT", &hints).is_ok());
+    /// assert!(Utc::parse_any("2024-01-09T16:21:00Z", &hints).is_err());
+    /// ```
+    pub fn formats(mut self, formats: &[Format]) -> Self {
+        self.formats = formats.to_vec();
+        self
+    }
+
+    /// Sets how numeric dates are read.
+    pub fn date_order(mut self, date_order: DateOrder) -> Self {
+        self.date_order = date_order;
+        self
+    }
+
+    /// Sets the unit of epoch timestamps.
+    pub fn epoch_unit(mut self, epoch_unit: EpochUnit) -> Self {
+        self.epoch_unit = epoch_unit;
+        self
+    }
+
+    /// Sets the year of formats which have none, such as
+    /// [`Format::Syslog`].
+    ///
+    /// Defaults to the current year. Without the `std` feature there is no
+    /// current year, so such formats are only recognized once it is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ ParseHints, Utc, };
+    ///
+    /// let hints = ParseHints::new().year(2023);
+    /// assert_eq!(
+    ///     Utc::from_ymdhms(2023, 1, 9, 16, 21, 0_f64),
+    ///     Utc::parse_any("Jan  9 16:21:00", &hints).unwrap()
+    /// );
+    /// ```
+    pub fn year(mut self, year: usize) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Sets the table which decides whether a second of 60 is a leap
+    /// second.
+    ///
+    /// Defaults to the [embedded table](crate::LeapSeconds::embedded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, ParseHints, Utc, };
+    ///
+    /// let leap = "2036-06-30T23:59:60Z";
+    /// assert!(Utc::parse_any(leap, &ParseHints::new()).is_err());
+    ///
+    /// let table = LeapSeconds::parse("3692217600 37\n4307472000 38").unwrap();
+    /// let dt = Utc::parse_any(leap, &ParseHints::new().leap_seconds(table)).unwrap();
+    /// assert!(dt.is_leap_second());
+    /// ```
+    pub fn leap_seconds(mut self, leap_seconds: crate::LeapSeconds) -> Self {
+        self.leap_seconds = leap_seconds;
+        self
+    }
+
+    /// Gets the formats to try, in order of priority.
+    pub(crate) fn formats_to_try(&self) -> &[Format] {
+        &self.formats
+    }
+
+    /// Gets the year to assume for formats which have none, reading the
+    /// clock only if such a format is to be tried.
+    pub(crate) fn year_to_assume(&self) -> Option<usize> {
+        if !self.formats.contains(&Format::Syslog) {
+            return None;
+        }
+
+        self.year.or_else(current_year)
+    }
+}
+
+/// An error encountered by [`Utc::parse_any`](crate::Utc::parse_any).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseAnyError {
+    /// The input does not look like any of the formats tried.
+    Unrecognized,
+    /// The input looks like the format but a field is out of range, e.g.
+    /// `2024-02-30`, or the date/time is before 0001 or after 9999.
+    Invalid(Format),
+    /// The input is a numeric date which reads differently day-first and
+    /// month-first.
+    Ambiguous {
+        /// The date/time when read day-first.
+        day_first: alloc::boxed::Box<crate::Utc>,
+        /// The date/time when read month-first.
+        month_first: alloc::boxed::Box<crate::Utc>,
+    },
+}
+
+impl core::fmt::Display for ParseAnyError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Unrecognized => fmtr.write_str("unrecognized date/time format"),
+            Self::Invalid(format) => write!(fmtr, "invalid {format:?} date/time"),
+            Self::Ambiguous { day_first, month_first, } => write!(
+                fmtr, "ambiguous date, could be {} or {}",
+                day_first.as_ymd(), month_first.as_ymd()
+            ),
+        }
+    }
+}
+
+impl core::error::Error for ParseAnyError {}
+
+/// Tries each of the hinted formats in order.
+pub(crate) fn parse_any(input: &str, hints: &ParseHints) -> Result<crate::Utc, ParseAnyError> {
+    let input = input.trim();
+    let year = hints.year_to_assume();
+    for format in &hints.formats {
+        let mut cursor = Cursor::new(input.as_bytes(), 0);
+        match parse_format(*format, &mut cursor, hints, year) {
+            Some(_) if !cursor.done() => continue,
+            Some(Parsed::Valid(dt)) => return Ok(dt),
+            Some(Parsed::Invalid) => return Err(ParseAnyError::Invalid(*format)),
+            Some(Parsed::Ambiguous(day_first, month_first)) => {
+                return Err(ParseAnyError::Ambiguous {
+                    day_first: alloc::boxed::Box::new(day_first),
+                    month_first: alloc::boxed::Box::new(month_first),
+                })
+            },
+            None => continue,
+        }
+    }
+
+    Err(ParseAnyError::Unrecognized)
+}
+
+/// Parses a single format starting at the cursor, leaving the cursor after
+/// the text which was read, with the year from
+/// [`ParseHints::year_to_assume`].
+pub(crate) fn parse_format(
+    format: Format, cursor: &mut Cursor, hints: &ParseHints, year: Option<usize>,
+) -> Option<Parsed> {
+    let leap_seconds = &hints.leap_seconds;
+    match format {
+        Format::Rfc3339 => rfc3339(cursor, leap_seconds),
+        Format::Iso8601 => iso8601(cursor, leap_seconds),
+        Format::Rfc7231 => rfc7231(cursor, leap_seconds),
+        Format::Epoch => epoch(cursor, hints.epoch_unit),
+        Format::NumericDate => numeric_date(cursor, hints.date_order, leap_seconds),
+        Format::NamedMonth => named_month(cursor, leap_seconds),
+        Format::Syslog => syslog(cursor, year?, leap_seconds),
+        Format::CommonLog => common_log(cursor, leap_seconds),
+    }
+}
+
+#[cfg(feature = "std")]
+fn current_year() -> Option<usize> {
+    Some(crate::Utc::now().year())
+}
+
+/// Without a clock there is no current year to assume.
+#[cfg(not(feature = "std"))]
+fn current_year() -> Option<usize> {
+    None
+}
+
+pub(crate) enum Parsed {
+    Valid(crate::Utc),
+    Invalid,
+    Ambiguous(crate::Utc, crate::Utc),
+}
+
+pub(crate) struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    timed: bool,
+}
+
+impl<'a> Cursor<'a> {
+    pub(crate) fn new(bytes: &'a [u8], pos: usize) -> Self {
+        Self { bytes, pos, timed: false, }
+    }
+
+    /// Gets the position of the next byte to read.
+    pub(crate) fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Whether a time of day has been read.
+    pub(crate) fn timed(&self) -> bool {
+        self.timed
+    }
+
+    fn done(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, b: u8) -> bool {
+        if self.peek() == Some(b) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_ignore_case(&mut self, s: &str) -> bool {
+        let end = self.pos + s.len();
+        if end <= self.bytes.len() && self.bytes[self.pos..end].eq_ignore_ascii_case(s.as_bytes()) {
+            self.pos = end;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn spaces(&mut self) -> usize {
+        let start = self.pos;
+        while self.peek() == Some(b' ') {
+            self.pos += 1;
+        }
+        self.pos - start
+    }
+
+    /// Reads between `min` and `max` digits.
+    fn digits(&mut self, min: usize, max: usize) -> Option<u64> {
+        let start = self.pos;
+        let mut value: u64 = 0;
+        while self.pos - start < max {
+            match self.peek() {
+                Some(b @ b'0'..=b'9') => {
+                    value = value * 10 + (b - b'0') as u64;
+                    self.pos += 1;
+                },
+                _ => break,
+            }
+        }
+
+        if self.pos - start < min {
+            self.pos = start;
+            return None;
+        }
+
+        Some(value)
+    }
+
+    /// Reads a fraction of a second after a `.` into nanoseconds, ignoring
+    /// digits beyond nanosecond precision.
+    fn fraction(&mut self) -> Option<u64> {
+        if !self.eat(b'.') {
+            return Some(0);
+        }
+
+        let start = self.pos;
+        let nanos = self.digits(1, 9)?;
+        let read = self.pos - start;
+        while self.peek().is_some_and(|b| b.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        Some(nanos * 10_u64.pow(9 - read as u32))
+    }
+
+    /// Reads a month name, full or abbreviated to three letters.
+    fn month_name(&mut self) -> Option<u64> {
+        for (i, name) in super::statics::M.iter().enumerate() {
+            if self.eat_ignore_case(name) || self.eat_ignore_case(&name[0..3]) {
+                return Some(i as u64 + 1);
+            }
+        }
+
+        None
+    }
+
+    /// Reads `HH:MM[:SS[.f]]`.
+    fn time(&mut self) -> Option<(u64, u64, u64, u64)> {
+        let hour = self.digits(2, 2)?;
+        if !self.eat(b':') {
+            return None;
+        }
+        let minute = self.digits(2, 2)?;
+        self.timed = true;
+        if !self.eat(b':') {
+            return Some((hour, minute, 0, 0));
+        }
+        let second = self.digits(2, 2)?;
+        Some((hour, minute, second, self.fraction()?))
+    }
+
+    /// Reads a `±HHMM` or `±HH:MM` offset east of UTC in seconds, or `None`
+    /// if there is no offset. Out of range offsets are returned as they are.
+    fn offset(&mut self, colon: bool) -> Option<i64> {
+        let sign = match self.peek() {
+            Some(b'+') => 1,
+            Some(b'-') => -1,
+            _ => return None,
+        };
+        let start = self.pos;
+        self.pos += 1;
+        let hours = self.digits(2, 2);
+        let separated = !colon || self.eat(b':');
+        match (hours, separated, self.digits(2, 2)) {
+            (Some(hours), true, Some(minutes)) if minutes < 60 => {
+                Some(sign * (hours * 3_600 + minutes * 60) as i64)
+            },
+            _ => {
+                self.pos = start;
+                None
+            },
+        }
+    }
+}
+
+/// Builds a date/time from fields, rejecting any which are out of range or
+/// a second of 60 which is not a leap second in the table, then shifts it
+/// back by an offset east of UTC.
+fn build(
+    (year, month, day): (u64, u64, u64),
+    (hour, minute, second, nanos): (u64, u64, u64, u64),
+    offset_seconds: i64,
+    leap_seconds: &crate::LeapSeconds,
+) -> Option<crate::Utc> {
+    let year = year as usize;
+    let month = month as usize;
+    let day = day as usize;
+    if !(super::statics::MIN_Y..=super::statics::MAX_Y).contains(&year) ||
+        !(1..=super::statics::MIY).contains(&month) ||
+        day < 1 || day > super::statics::DIM[month - 1][super::statics::ly(year)] ||
+        hour > 23 || minute > 59 || second > 60
+    {
+        return None;
+    }
+
+    // A leap second must be one once the offset is applied.
+    if second == 60 {
+        let before = build(
+            (year as u64, month as u64, day as u64), (hour, minute, 59, nanos), offset_seconds,
+            leap_seconds,
+        )?;
+        let is_leap = before.hour() == 23 && before.minute() == 59 && before.second() == 59 &&
+            leap_seconds.has_leap_second(before.year(), before.month(), before.day());
+        return is_leap.then(|| before.leap_second_after(before.nano()));
+    }
+
+    let dt = crate::Utc::from_ymdhms(
+        year, month, day, hour as usize, minute as usize, second as f64,
+    );
+    crate::Utc::from_nanos_since_epoch(
+        dt.nanos_since_epoch() + nanos as i128 - offset_seconds as i128 * 1_000_000_000
+    )
+}
+
+fn valid(dt: Option<crate::Utc>) -> Parsed {
+    match dt {
+        Some(dt) => Parsed::Valid(dt),
+        None => Parsed::Invalid,
+    }
+}
+
+fn ymd(cursor: &mut Cursor) -> Option<(u64, u64, u64)> {
+    let year = cursor.digits(4, 4)?;
+    if !cursor.eat(b'-') {
+        return None;
+    }
+    let month = cursor.digits(2, 2)?;
+    if !cursor.eat(b'-') {
+        return None;
+    }
+    let day = cursor.digits(2, 2)?;
+    Some((year, month, day))
+}
+
+fn rfc3339(cursor: &mut Cursor, leap_seconds: &crate::LeapSeconds) -> Option<Parsed> {
+    let date = ymd(cursor)?;
+    if !cursor.eat(b'T') && !cursor.eat(b't') {
+        return None;
+    }
+    let time = cursor.time()?;
+
+    let offset = if cursor.eat(b'Z') || cursor.eat(b'z') {
+        0
+    } else {
+        cursor.offset(true).unwrap_or(0)
+    };
+    if offset.abs() >= 86_400 {
+        return Some(Parsed::Invalid);
+    }
+
+    Some(valid(build(date, time, offset, leap_seconds)))
+}
+
+fn iso8601(cursor: &mut Cursor, leap_seconds: &crate::LeapSeconds) -> Option<Parsed> {
+    let date = ymd(cursor)?;
+    let start = cursor.pos;
+    let time = match cursor.eat(b' ').then(|| cursor.time()).flatten() {
+        Some(time) => time,
+        None => {
+            cursor.pos = start;
+            (0, 0, 0, 0)
+        },
+    };
+
+    Some(valid(build(date, time, 0, leap_seconds)))
+}
+
+fn rfc7231(cursor: &mut Cursor, leap_seconds: &crate::LeapSeconds) -> Option<Parsed> {
+    let weekday = super::statics::D.iter().position(|d| cursor.eat_ignore_case(&d[0..3]))?;
+    if !cursor.eat(b',') || cursor.spaces() != 1 {
+        return None;
+    }
+    let day = cursor.digits(2, 2)?;
+    cursor.spaces();
+    let month = cursor.month_name()?;
+    cursor.spaces();
+    let year = cursor.digits(4, 4)?;
+    cursor.spaces();
+    let time = cursor.time()?;
+    cursor.spaces();
+    if !cursor.eat_ignore_case("GMT") {
+        return None;
+    }
+
+    Some(match build((year, month, day), time, 0, leap_seconds) {
+        Some(dt) if dt.day_of_week() == super::statics::D[weekday] => Parsed::Valid(dt),
+        _ => Parsed::Invalid,
+    })
+}
+
+fn epoch(cursor: &mut Cursor, unit: EpochUnit) -> Option<Parsed> {
+    let whole = cursor.digits(1, 19)?;
+    let nanos = cursor.fraction()?;
+
+    let millis = match unit {
+        EpochUnit::Auto => whole >= 100_000_000_000,
+        EpochUnit::Seconds => false,
+        EpochUnit::Millis => true,
+    };
+    let total = if millis {
+        whole as i128 * 1_000_000 + nanos as i128 / 1_000
+    } else {
+        whole as i128 * 1_000_000_000 + nanos as i128
+    };
+
+    Some(valid(crate::Utc::from_nanos_since_epoch(total)))
+}
+
+/// Reads an optional ` HH:MM[:SS[.f]]` after a date.
+fn trailing_time(cursor: &mut Cursor) -> (u64, u64, u64, u64) {
+    let start = cursor.pos;
+    if cursor.spaces() > 0 {
+        if cursor.eat_ignore_case("at ") {
+            cursor.spaces();
+        }
+        if let Some(time) = cursor.time() {
+            return time;
+        }
+    }
+
+    cursor.pos = start;
+    (0, 0, 0, 0)
+}
+
+fn numeric_date(
+    cursor: &mut Cursor, order: DateOrder, leap_seconds: &crate::LeapSeconds,
+) -> Option<Parsed> {
+    let first = cursor.digits(1, 2)?;
+    let separator = match cursor.peek() {
+        Some(b @ (b'/' | b'.' | b'-')) => b,
+        _ => return None,
+    };
+    cursor.pos += 1;
+    let second = cursor.digits(1, 2)?;
+    if !cursor.eat(separator) {
+        return None;
+    }
+    let year = cursor.digits(4, 4)?;
+    let time = trailing_time(cursor);
+
+    let day_first = build((year, second, first), time, 0, leap_seconds);
+    let month_first = build((year, first, second), time, 0, leap_seconds);
+    Some(match (order, day_first, month_first) {
+        (DateOrder::DayFirst, dt, _) | (DateOrder::MonthFirst, _, dt) => valid(dt),
+        (DateOrder::Unknown, Some(a), Some(b)) if first != second => Parsed::Ambiguous(a, b),
+        (DateOrder::Unknown, Some(dt), _) | (DateOrder::Unknown, None, Some(dt)) => Parsed::Valid(dt),
+        (DateOrder::Unknown, None, None) => Parsed::Invalid,
+    })
+}
+
+fn named_month(cursor: &mut Cursor, leap_seconds: &crate::LeapSeconds) -> Option<Parsed> {
+    let (day, month) = match cursor.digits(1, 2) {
+        Some(day) => {
+            cursor.spaces();
+            (day, cursor.month_name()?)
+        },
+        None => {
+            let month = cursor.month_name()?;
+            cursor.spaces();
+            let day = cursor.digits(1, 2)?;
+            cursor.eat(b',');
+            (day, month)
+        },
+    };
+
+    if cursor.spaces() == 0 {
+        return None;
+    }
+    let year = cursor.digits(4, 4)?;
+    let time = trailing_time(cursor);
+
+    Some(valid(build((year, month, day), time, 0, leap_seconds)))
+}
+
+fn syslog(
+    cursor: &mut Cursor, year: usize, leap_seconds: &crate::LeapSeconds,
+) -> Option<Parsed> {
+    let month = cursor.month_name()?;
+    if !(1..=2).contains(&cursor.spaces()) {
+        return None;
+    }
+    let day = cursor.digits(1, 2)?;
+    if cursor.spaces() != 1 {
+        return None;
+    }
+    let time = cursor.time()?;
+
+    Some(valid(build((year as u64, month, day), time, 0, leap_seconds)))
+}
+
+fn common_log(cursor: &mut Cursor, leap_seconds: &crate::LeapSeconds) -> Option<Parsed> {
+    let day = cursor.digits(2, 2)?;
+    if !cursor.eat(b'/') {
+        return None;
+    }
+    let month = cursor.month_name()?;
+    if !cursor.eat(b'/') {
+        return None;
+    }
+    let year = cursor.digits(4, 4)?;
+    if !cursor.eat(b':') {
+        return None;
+    }
+    let time = cursor.time()?;
+    if cursor.spaces() != 1 {
+        return None;
+    }
+    let offset = cursor.offset(false)?;
+    if offset.abs() >= 86_400 {
+        return Some(Parsed::Invalid);
+    }
+
+    Some(valid(build((year, month, day), time, offset, leap_seconds)))
+}
@@ -0,0 +1,49 @@
+/// A component of a date/time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Component {
+    /// The year.
+    Year,
+    /// The month of the year.
+    Month,
+    /// The day of the month.
+    Day,
+    /// The hour of the day.
+    Hour,
+    /// The minute of the hour.
+    Minute,
+    /// The second of the minute.
+    Second,
+}
+
+/// A date/time component which is outside of its valid range.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RangeError {
+    component: Component,
+}
+
+impl RangeError {
+    pub(crate) fn new(component: Component) -> Self {
+        Self { component, }
+    }
+
+    /// Gets the component which is out of range.
+    pub fn component(&self) -> Component {
+        self.component
+    }
+}
+
+impl core::fmt::Display for RangeError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let component = match self.component {
+            Component::Year => "year",
+            Component::Month => "month",
+            Component::Day => "day",
+            Component::Hour => "hour",
+            Component::Minute => "minute",
+            Component::Second => "second",
+        };
+        write!(fmtr, "{component} is out of range")
+    }
+}
+
+impl core::error::Error for RangeError {}
@@ -0,0 +1,211 @@
+/// Describes one date/time relative to another in English, e.g.
+/// `3 hours ago`, `yesterday`, `last Tuesday` or `in 3 weeks`.
+///
+/// # Examples
+///
+/// ```
+/// let now = utc::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64);
+/// let fmt = utc::RelativeFormat::new();
+///
+/// let earlier = utc::Utc::from_ymdhms(2024, 1, 9, 9, 0, 0_f64);
+/// assert_eq!("3 hours ago", fmt.format(&earlier, &now));
+///
+/// let later = utc::Utc::from_ymd(2024, 1, 30);
+/// assert_eq!("in 3 weeks", fmt.format(&later, &now));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RelativeFormat {
+    just_now: core::time::Duration,
+    minute: core::time::Duration,
+    hour: core::time::Duration,
+    day: core::time::Duration,
+    week: core::time::Duration,
+    month: core::time::Duration,
+    weekdays: bool,
+    cutoff: Option<core::time::Duration>,
+}
+
+impl Default for RelativeFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RelativeFormat {
+    /// Gets the default format: differences under ten seconds are
+    /// `just now`, each unit is used once there is one whole of it, except
+    /// that days are used from twelve hours apart on different days, the
+    /// past and coming week use weekday names and there is no cutoff.
+    pub fn new() -> Self {
+        Self {
+            just_now: core::time::Duration::from_secs(10),
+            minute: core::time::Duration::from_secs(60),
+            hour: core::time::Duration::from_secs(3_600),
+            day: core::time::Duration::from_secs(12 * 3_600),
+            week: core::time::Duration::from_secs(7 * 86_400),
+            month: core::time::Duration::from_secs(28 * 86_400),
+            weekdays: true,
+            cutoff: None,
+        }
+    }
+
+    /// Sets the difference under which date/times are described as
+    /// `just now`.
+    pub fn just_now(mut self, threshold: core::time::Duration) -> Self {
+        self.just_now = threshold;
+        self
+    }
+
+    /// Sets the difference from which minutes are used rather than
+    /// seconds, one minute by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let now = utc::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64);
+    /// let then = utc::Utc::from_ymdhms(2024, 1, 9, 11, 58, 45_f64);
+    /// let fmt = utc::RelativeFormat::new();
+    /// assert_eq!("1 minute ago", fmt.format(&then, &now));
+    /// let fmt = fmt.minute(std::time::Duration::from_secs(90));
+    /// assert_eq!("75 seconds ago", fmt.format(&then, &now));
+    /// ```
+    pub fn minute(mut self, threshold: core::time::Duration) -> Self {
+        self.minute = threshold;
+        self
+    }
+
+    /// Sets the difference from which hours are used rather than minutes,
+    /// one hour by default.
+    pub fn hour(mut self, threshold: core::time::Duration) -> Self {
+        self.hour = threshold;
+        self
+    }
+
+    /// Sets the difference from which date/times on different days are
+    /// described by day (`yesterday`, `last Tuesday`, `5 days ago`) rather
+    /// than by hours, twelve hours by default.
+    pub fn day(mut self, threshold: core::time::Duration) -> Self {
+        self.day = threshold;
+        self
+    }
+
+    /// Sets the difference, in whole days between the dates, from which
+    /// weeks are used rather than days, seven days by default. Weekday
+    /// names are still only used up to six days.
+    pub fn week(mut self, threshold: core::time::Duration) -> Self {
+        self.week = threshold;
+        self
+    }
+
+    /// Sets the difference from which months are used rather than weeks,
+    /// once at least one whole month is between the dates, 28 days by
+    /// default.
+    pub fn month(mut self, threshold: core::time::Duration) -> Self {
+        self.month = threshold;
+        self
+    }
+
+    /// Sets whether differences of two to six days are described by
+    /// weekday (`last Tuesday`) rather than by count (`5 days ago`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let now = utc::Utc::from_ymd(2024, 1, 9);
+    /// let then = utc::Utc::from_ymd(2024, 1, 4);
+    /// let fmt = utc::RelativeFormat::new();
+    /// assert_eq!("last Thursday", fmt.format(&then, &now));
+    /// assert_eq!("5 days ago", fmt.weekdays(false).format(&then, &now));
+    /// ```
+    pub fn weekdays(mut self, weekdays: bool) -> Self {
+        self.weekdays = weekdays;
+        self
+    }
+
+    /// Sets the difference at or beyond which the date is shown in
+    /// yyyy-MM-dd format instead of a phrase.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let now = utc::Utc::from_ymd(2024, 1, 9);
+    /// let then = utc::Utc::from_ymd(2023, 11, 2);
+    /// let fmt = utc::RelativeFormat::new()
+    ///     .cutoff(Some(std::time::Duration::from_secs(30 * 86_400)));
+    /// assert_eq!("2023-11-02", fmt.format(&then, &now));
+    /// ```
+    pub fn cutoff(mut self, cutoff: Option<core::time::Duration>) -> Self {
+        self.cutoff = cutoff;
+        self
+    }
+
+    /// Describes `dt` relative to `reference`.
+    pub fn format(&self, dt: &crate::Utc, reference: &crate::Utc) -> alloc::string::String {
+        let past = dt < reference;
+        let (earlier, later) = if past { (dt, reference) } else { (reference, dt) };
+        let diff = later.duration_since(earlier).unwrap();
+
+        if diff < self.just_now {
+            return "just now".into();
+        }
+
+        if self.cutoff.is_some_and(|cutoff| diff >= cutoff) {
+            return dt.as_ymd();
+        }
+
+        let seconds = diff.as_secs();
+        if diff < self.minute {
+            return phrase(seconds, "second", past);
+        }
+
+        if diff < self.hour {
+            return phrase((seconds / 60).max(1), "minute", past);
+        }
+
+        let days = later.days_since_epoch() - earlier.days_since_epoch();
+        if days == 0 || diff < self.day {
+            return phrase((seconds / 3_600).max(1), "hour", past);
+        }
+
+        if days == 1 {
+            return if past { "yesterday" } else { "tomorrow" }.into();
+        }
+
+        if (days as u64) < self.week.as_secs() / 86_400 {
+            if self.weekdays && days < 7 {
+                let when = if past { "last" } else { "next" };
+                return alloc::format!("{when} {}", dt.day_of_week());
+            }
+
+            return phrase(days as u64, "day", past);
+        }
+
+        let mut months = (later.year() * super::statics::MIY + later.month()) -
+            (earlier.year() * super::statics::MIY + earlier.month());
+        let within_month = |dt: &crate::Utc| {
+            (dt.day(), dt.hour(), dt.minute(), dt.second(), dt.nanosecond())
+        };
+        if within_month(later) < within_month(earlier) {
+            months -= 1;
+        }
+
+        if months == 0 || diff < self.month {
+            return phrase((days as u64 / 7).max(1), "week", past);
+        }
+
+        if months < super::statics::MIY {
+            return phrase(months as u64, "month", past);
+        }
+
+        phrase((months / super::statics::MIY) as u64, "year", past)
+    }
+}
+
+fn phrase(count: u64, unit: &str, past: bool) -> alloc::string::String {
+    let plural = if count == 1 { "" } else { "s" };
+    if past {
+        alloc::format!("{count} {unit}{plural} ago")
+    } else {
+        alloc::format!("in {count} {unit}{plural}")
+    }
+}
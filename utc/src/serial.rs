@@ -0,0 +1,240 @@
+/// The number of milliseconds in a day.
+const MILLIS_PER_DAY: i64 = 86_400_000;
+
+/// Days from the unix epoch to 1899-12-30, the day before Excel's day 1 and
+/// OLE Automation's day 0.
+const DAY_ZERO_1899: i64 = crate::statics::days_from_civil(1899, 12, 30);
+
+/// Days from the unix epoch to 1900-03-01, the first day Excel's 1900 date
+/// system counts correctly.
+const MARCH_1900: i64 = crate::statics::days_from_civil(1900, 3, 1);
+
+/// Days from the unix epoch to 1904-01-01, day 0 of Excel's 1904 date
+/// system.
+const DAY_ZERO_1904: i64 = crate::statics::days_from_civil(1904, 1, 1);
+
+/// Gets the number of milliseconds from the unix epoch to a date/time,
+/// rounded to the nearest, halves up.
+const fn millis_since_epoch(dt: &crate::Utc) -> i64 {
+    (dt.nanos_since_epoch() + 500_000).div_euclid(1_000_000) as i64
+}
+
+/// Gets the date/time a number of milliseconds from the unix epoch, or
+/// `None` if it is out of range.
+const fn from_millis_since_epoch(millis: i64) -> Option<crate::Utc> {
+    crate::Utc::from_nanos_since_epoch(millis as i128 * 1_000_000)
+}
+
+/// Gets the number of milliseconds in a number of days, rounded to the
+/// nearest, or `None` if it is not finite or too large.
+const fn millis_from_days(days: f64) -> Option<i64> {
+    // Far beyond 9999 in any of the systems.
+    if !days.is_finite() || days.abs() > 1e8 {
+        return None;
+    }
+
+    Some(crate::statics::round(days * MILLIS_PER_DAY as f64) as i64)
+}
+
+/// Gets the number of days in a number of milliseconds.
+const fn days_from_millis(millis: i64) -> f64 {
+    millis as f64 / MILLIS_PER_DAY as f64
+}
+
+/// A date system of Excel spreadsheets, in which cells hold dates as serial
+/// numbers: the number of days since day 0, with the time of day as the
+/// fraction.
+///
+/// Conversions are exact to the millisecond, as Excel is, and round to it.
+/// A leap second, 23:59:60, gets the serial of the midnight after it.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ ExcelDateSystem, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 18, 0, 0_f64);
+/// assert_eq!(Some(45_300.75), ExcelDateSystem::Date1900.to_serial(&dt));
+/// assert_eq!(Some(43_838.75), ExcelDateSystem::Date1904.to_serial(&dt));
+/// assert_eq!(Ok(dt), ExcelDateSystem::Date1900.from_serial(45_300.75));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ExcelDateSystem {
+    /// The default date system, in which day 1 is 1900-01-01.
+    ///
+    /// For compatibility with Lotus 1-2-3 it takes 1900 as a leap year, so
+    /// day 60 is the fictitious 1900-02-29 and the serials of the days
+    /// before it are one less than the days since 1899-12-30. Day 0 is
+    /// shown by Excel as 1900-01-00 and taken here as 1899-12-31.
+    #[default]
+    Date1900,
+    /// The date system of early Excel for Mac, in which day 0 is
+    /// 1904-01-01.
+    Date1904,
+}
+
+impl ExcelDateSystem {
+    /// Gets the serial of a date/time, or `None` if it is before day 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ ExcelDateSystem, Utc, };
+    ///
+    /// let system = ExcelDateSystem::Date1900;
+    /// assert_eq!(Some(59.5), system.to_serial(&Utc::from_ymdhms(1900, 2, 28, 12, 0, 0_f64)));
+    /// assert_eq!(Some(61_f64), system.to_serial(&Utc::from_ymd(1900, 3, 1)));
+    /// assert_eq!(None, ExcelDateSystem::Date1904.to_serial(&Utc::from_ymd(1903, 12, 31)));
+    /// ```
+    pub const fn to_serial(&self, dt: &crate::Utc) -> Option<f64> {
+        let millis = millis_since_epoch(dt);
+        let serial = match self {
+            Self::Date1900 if millis < MARCH_1900 * MILLIS_PER_DAY => {
+                millis - (DAY_ZERO_1899 + 1) * MILLIS_PER_DAY
+            },
+            Self::Date1900 => millis - DAY_ZERO_1899 * MILLIS_PER_DAY,
+            Self::Date1904 => millis - DAY_ZERO_1904 * MILLIS_PER_DAY,
+        };
+        if serial < 0 {
+            return None;
+        }
+
+        Some(days_from_millis(serial))
+    }
+
+    /// Gets the date/time of a serial, rounded to the millisecond.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the [`Component::Day`] if the serial is on the
+    /// fictitious 1900-02-29, and for the [`Component::Year`] if it is
+    /// negative, not finite or after 9999.
+    ///
+    /// [`Component::Day`]: crate::Component::Day
+    /// [`Component::Year`]: crate::Component::Year
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ Component, ExcelDateSystem, Utc, };
+    ///
+    /// let system = ExcelDateSystem::Date1900;
+    /// assert_eq!(Ok(Utc::from_ymd(1900, 2, 28)), system.from_serial(59_f64));
+    /// assert_eq!(Component::Day, system.from_serial(60.25).unwrap_err().component());
+    /// assert_eq!(Ok(Utc::from_ymd(1900, 3, 1)), system.from_serial(61_f64));
+    /// ```
+    pub fn from_serial(&self, serial: f64) -> Result<crate::Utc, crate::RangeError> {
+        let out_of_range = crate::RangeError::new(crate::Component::Year);
+        let millis = millis_from_days(serial).ok_or(out_of_range)?;
+        if millis < 0 {
+            return Err(out_of_range);
+        }
+
+        let millis = match self {
+            Self::Date1900 if millis < 60 * MILLIS_PER_DAY => {
+                millis + (DAY_ZERO_1899 + 1) * MILLIS_PER_DAY
+            },
+            Self::Date1900 if millis < 61 * MILLIS_PER_DAY => {
+                return Err(crate::RangeError::new(crate::Component::Day));
+            },
+            Self::Date1900 => millis + DAY_ZERO_1899 * MILLIS_PER_DAY,
+            Self::Date1904 => millis + DAY_ZERO_1904 * MILLIS_PER_DAY,
+        };
+        from_millis_since_epoch(millis).ok_or(out_of_range)
+    }
+}
+
+/// An OLE Automation `DATE`, as used by COM, VBA and .NET's
+/// `DateTime.ToOADate`: the number of days since 1899-12-30, with the time
+/// of day as the fraction.
+///
+/// Before day 0 the whole days count back but the fraction still counts
+/// forward, so -1.25 is 1899-12-29T06:00:00, and -0.5 is the same instant as
+/// 0.5. `DATE`s are valid from 0100-01-01 to 9999-12-31.
+///
+/// Conversions are exact to the millisecond and round to it. A leap second,
+/// 23:59:60, gets the `DATE` of the midnight after it.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ OleDate, Utc, };
+///
+/// let dt = Utc::from_ymdhms(1899, 12, 29, 6, 0, 0_f64);
+/// assert_eq!(Some(OleDate::new(-1.25)), OleDate::from_utc(&dt));
+/// assert_eq!(Some(dt), OleDate::new(-1.25).to_utc());
+/// assert_eq!(OleDate::new(0.5).to_utc(), OleDate::new(-0.5).to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct OleDate {
+    value: f64,
+}
+
+impl OleDate {
+    /// Gets a `DATE` from its value.
+    pub const fn new(value: f64) -> Self {
+        Self { value, }
+    }
+
+    /// Gets the value.
+    pub const fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Gets the `DATE` of a date/time, or `None` if it is before 0100.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ OleDate, Utc, };
+    ///
+    /// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// assert_eq!(45_300.681255787, (OleDate::from_utc(&dt).unwrap().value() * 1e9).round() / 1e9);
+    /// assert_eq!(None, OleDate::from_utc(&Utc::from_ymd(99, 12, 31)));
+    /// ```
+    pub const fn from_utc(dt: &crate::Utc) -> Option<Self> {
+        if dt.year() < 100 {
+            return None;
+        }
+
+        let mut millis = millis_since_epoch(dt) - DAY_ZERO_1899 * MILLIS_PER_DAY;
+        if millis < 0 {
+            // Count the days back and the time of day forward.
+            let time = millis % MILLIS_PER_DAY;
+            if time != 0 {
+                millis -= (MILLIS_PER_DAY + time) * 2;
+            }
+        }
+
+        Some(Self { value: days_from_millis(millis), })
+    }
+
+    /// Gets the date/time, rounded to the millisecond, or `None` if it is
+    /// not a valid `DATE`.
+    pub const fn to_utc(&self) -> Option<crate::Utc> {
+        let mut millis = match millis_from_days(self.value) {
+            Some(millis) => millis,
+            None => return None,
+        };
+        if millis < 0 {
+            millis -= millis % MILLIS_PER_DAY * 2;
+        }
+
+        match from_millis_since_epoch(millis + DAY_ZERO_1899 * MILLIS_PER_DAY) {
+            Some(dt) if dt.year() >= 100 => Some(dt),
+            _ => None,
+        }
+    }
+}
+
+impl From<f64> for OleDate {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<OleDate> for f64 {
+    fn from(date: OleDate) -> Self {
+        date.value
+    }
+}
@@ -0,0 +1,229 @@
+/// How a leap smear spreads the leap second over its window.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SmearShape {
+    /// The smeared clock runs at a constant slower or faster rate for the
+    /// whole window.
+    #[default]
+    Linear,
+    /// The smeared clock's rate changes gradually, following half a cosine,
+    /// so that there is no sudden change in rate at either end.
+    Cosine,
+}
+
+impl SmearShape {
+    /// Gets how much of the leap second has been spread after a fraction of
+    /// the window.
+    const fn spread(&self, fraction: f64) -> f64 {
+        match self {
+            Self::Linear => fraction,
+            Self::Cosine => {
+                (1_f64 - crate::statics::cos(core::f64::consts::PI * fraction)) / 2_f64
+            },
+        }
+    }
+}
+
+/// A leap smear, which instead of inserting 23:59:60 slows the clock down
+/// around each leap second so that it never has to step.
+///
+/// By default each leap second in the [embedded](crate::LeapSeconds::embedded)
+/// table is spread linearly over the 24 hours from noon to noon UTC around
+/// it, as done by the public NTP servers of Google and others. Outside of
+/// the windows smeared time is UTC. Windows should be shorter than the time
+/// between leap seconds.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ LeapSmear, Utc, };
+///
+/// let smear = LeapSmear::new();
+/// let leap = Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64);
+/// let smeared = smear.smear(&leap);
+/// assert_eq!("2016-12-31T23:59:59.500", smeared.to_rfc3339_with_nano_buf(3));
+/// assert_eq!(leap, smear.unsmear(&smeared));
+///
+/// let noon = Utc::from_ymdhms(2016, 12, 31, 12, 0, 0_f64);
+/// assert_eq!(noon, smear.smear(&noon));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeapSmear {
+    window: core::time::Duration,
+    shape: SmearShape,
+    leap_seconds: crate::LeapSeconds,
+}
+
+impl LeapSmear {
+    /// Gets the noon to noon linear smear of the embedded leap seconds.
+    pub const fn new() -> Self {
+        Self {
+            window: core::time::Duration::from_secs(86_400),
+            shape: SmearShape::Linear,
+            leap_seconds: crate::LeapSeconds::embedded(),
+        }
+    }
+
+    /// Sets how long each leap second is smeared over, centred on it, at
+    /// least two seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ LeapSmear, Utc, };
+    ///
+    /// let smear = LeapSmear::new().window(Duration::from_secs(1_000));
+    /// let before = Utc::from_ymdhms(2016, 12, 31, 23, 51, 0_f64);
+    /// assert_eq!(before, smear.smear(&before));
+    /// ```
+    pub fn window(mut self, window: core::time::Duration) -> Self {
+        self.window = window.max(core::time::Duration::from_secs(2));
+        self
+    }
+
+    /// Sets how each leap second is spread over its window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSmear, SmearShape, Utc, };
+    ///
+    /// let smear = LeapSmear::new().shape(SmearShape::Cosine);
+    /// let dt = Utc::from_ymdhms(2016, 12, 31, 18, 0, 0_f64);
+    /// assert!(smear.smear(&dt) > LeapSmear::new().smear(&dt));
+    /// ```
+    pub fn shape(mut self, shape: SmearShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the leap second table to smear.
+    pub fn leap_seconds(mut self, leap_seconds: crate::LeapSeconds) -> Self {
+        self.leap_seconds = leap_seconds;
+        self
+    }
+
+    /// Gets the smeared date/time of a UTC date/time, which may be a leap
+    /// second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the smeared date/time is before 0001 or after 9999.
+    pub fn smear(&self, dt: &crate::Utc) -> crate::Utc {
+        let tai = self.leap_seconds.tai_nanos(dt);
+        self.leap_seconds.steps()
+            .find_map(|step| self.smear_step(tai, step))
+            .map_or(*dt, |nanos| {
+                crate::Utc::from_nanos_since_epoch(nanos).expect("Date must be < 10000")
+            })
+    }
+
+    /// Gets the UTC date/time of a smeared date/time, e.g. a reading from a
+    /// host synchronised to a smearing time server.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the UTC date/time is before 0001 or after 9999.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSmear, Utc, };
+    ///
+    /// let smeared = Utc::from_ymd(2017, 1, 1);
+    /// assert_eq!(
+    ///     "2016-12-31T23:59:60.500000",
+    ///     LeapSmear::new().unsmear(&smeared).to_rfc3339_with_nano_buf(6)
+    /// );
+    /// ```
+    pub fn unsmear(&self, smeared: &crate::Utc) -> crate::Utc {
+        let nanos = smeared.nanos_since_epoch();
+        let window = self.window.as_nanos() as i128;
+        for step @ (at, before, after) in self.leap_seconds.steps() {
+            let start = at - window / 2;
+            if nanos < start || nanos >= start + window {
+                continue;
+            }
+
+            // Smeared time only ever moves forward, so search for the
+            // earliest TAI instant which smears to it.
+            let (mut low, mut high) = (start + before, start + window + after);
+            while low < high {
+                let mid = low + (high - low) / 2;
+                if self.smear_step(mid, step).is_some_and(|mid| mid >= nanos) {
+                    high = mid;
+                } else {
+                    low = mid + 1;
+                }
+            }
+
+            return self.leap_seconds.utc_from_tai_nanos(low).expect("Date must be >= 0001");
+        }
+
+        *smeared
+    }
+
+    /// Gets the smeared nanoseconds since the unix epoch of a TAI instant
+    /// if it is in the window around the change in TAI − UTC.
+    fn smear_step(&self, tai: i128, (at, before, after): (i128, i128, i128)) -> Option<i128> {
+        let window = self.window.as_nanos() as i128;
+        let start = at - window / 2 + before;
+        let length = window + after - before;
+        if tai < start || tai >= start + length {
+            return None;
+        }
+
+        let fraction = (tai - start) as f64 / length as f64;
+        let spread = crate::statics::round(self.shape.spread(fraction) * (after - before) as f64);
+        Some(tai - before - spread as i128)
+    }
+}
+
+impl Default for LeapSmear {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A clock which smears the leap seconds out of another clock's readings.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Clock, FixedClock, LeapSmear, SmearedClock, Utc, };
+///
+/// let clock = FixedClock::new(Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64));
+/// let smeared = SmearedClock::new(clock, LeapSmear::new());
+/// assert!(!smeared.now().is_leap_second());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmearedClock<C> {
+    clock: C,
+    smear: LeapSmear,
+}
+
+impl<C: crate::Clock> SmearedClock<C> {
+    /// Gets a clock smearing another clock's readings.
+    pub const fn new(clock: C, smear: LeapSmear) -> Self {
+        Self { clock, smear, }
+    }
+
+    /// Gets the clock being smeared.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+
+    /// Gets the smear applied to the readings.
+    pub fn smear(&self) -> &LeapSmear {
+        &self.smear
+    }
+}
+
+impl<C: crate::Clock> crate::Clock for SmearedClock<C> {
+    /// # Panics
+    ///
+    /// Panics if the smeared date/time is before 0001 or after 9999.
+    fn now(&self) -> crate::Utc {
+        self.smear.smear(&self.clock.now())
+    }
+}
@@ -0,0 +1,256 @@
+/// The length of an SNTP packet without extensions or authentication.
+const PACKET_LEN: usize = 48;
+
+/// A minimal SNTP client, as in RFC 4330, for measuring how far the local
+/// clock is from a time server.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use utc::SntpClient;
+///
+/// let response = SntpClient::new("pool.ntp.org:123")
+///     .timeout(Duration::from_secs(2))
+///     .query()
+///     .unwrap();
+/// println!("{} offset {} ns", response.server_time(), response.offset_nanos());
+/// ```
+#[derive(Clone, Debug)]
+pub struct SntpClient<C = crate::SystemClock> {
+    server: alloc::string::String,
+    timeout: core::time::Duration,
+    clock: C,
+}
+
+impl SntpClient {
+    /// Gets a client querying a server, given as `host:port`, from the
+    /// system's wall clock.
+    pub fn new(server: impl Into<alloc::string::String>) -> Self {
+        Self::with_clock(server, crate::SystemClock)
+    }
+}
+
+impl<C: crate::Clock> SntpClient<C> {
+    /// Gets a client querying a server from another clock, whose offset is
+    /// measured.
+    pub fn with_clock(server: impl Into<alloc::string::String>, clock: C) -> Self {
+        Self { server: server.into(), timeout: core::time::Duration::from_secs(5), clock, }
+    }
+
+    /// Sets how long to wait for the server to reply, five seconds by
+    /// default.
+    pub fn timeout(mut self, timeout: core::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Gets the clock being measured.
+    pub fn inner(&self) -> &C {
+        &self.clock
+    }
+
+    /// Sends a request to the server and waits for its reply.
+    pub fn query(&self) -> Result<SntpResponse, SntpError> {
+        let server = std::net::ToSocketAddrs::to_socket_addrs(self.server.as_str())
+            .map_err(SntpError::Io)?
+            .next()
+            .ok_or_else(|| SntpError::Io(std::io::ErrorKind::NotFound.into()))?;
+        let local: std::net::SocketAddr = if server.is_ipv4() {
+            (std::net::Ipv4Addr::UNSPECIFIED, 0).into()
+        } else {
+            (std::net::Ipv6Addr::UNSPECIFIED, 0).into()
+        };
+        let socket = std::net::UdpSocket::bind(local).map_err(SntpError::Io)?;
+        socket.connect(server).map_err(SntpError::Io)?;
+        // A zero timeout would mean waiting forever.
+        socket.set_read_timeout(Some(self.timeout.max(core::time::Duration::from_nanos(1))))
+            .map_err(SntpError::Io)?;
+
+        // Version 4, client mode, with the send time as the transmit
+        // timestamp for the server to echo back.
+        let mut request = [0; PACKET_LEN];
+        request[0] = 4 << 3 | 3;
+        let sent = self.clock.now();
+        let origin = crate::NtpTimestamp::from_utc(&sent).to_bytes();
+        request[40..].copy_from_slice(&origin);
+        socket.send(&request).map_err(SntpError::Io)?;
+
+        let mut reply = [0; PACKET_LEN];
+        let len = socket.recv(&mut reply).map_err(|e| match e.kind() {
+            std::io::ErrorKind::WouldBlock | std::io::ErrorKind::TimedOut => SntpError::Timeout,
+            _ => SntpError::Io(e),
+        })?;
+        let received = self.clock.now();
+
+        SntpResponse::parse(&reply[..len], origin, &sent, &received)
+    }
+}
+
+/// The reply to an SNTP query.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SntpResponse {
+    server_time: crate::Utc,
+    delay: core::time::Duration,
+    offset: i64,
+    stratum: u8,
+    root_delay: crate::NtpShort,
+    root_dispersion: crate::NtpShort,
+}
+
+impl SntpResponse {
+    /// Checks a reply to a request with the origin timestamp, sent and
+    /// received at the local times.
+    fn parse(
+        reply: &[u8], origin: [u8; 8], sent: &crate::Utc, received: &crate::Utc,
+    ) -> Result<Self, SntpError> {
+        if reply.len() < PACKET_LEN || reply[0] & 0x07 != 4 || reply[0] >> 3 & 0x07 == 0 {
+            return Err(SntpError::InvalidResponse);
+        }
+
+        // The origin timestamp must be ours, or the reply is not to our
+        // request.
+        if reply[24..32] != origin {
+            return Err(SntpError::InvalidResponse);
+        }
+
+        let stratum = reply[1];
+        if stratum == 0 {
+            let mut code = [0; 4];
+            code.copy_from_slice(&reply[12..16]);
+            return Err(SntpError::KissOfDeath(code));
+        }
+
+        if reply[0] >> 6 == 3 {
+            return Err(SntpError::Unsynchronized);
+        }
+
+        let timestamp = |at: usize| {
+            let mut bytes = [0; 8];
+            bytes.copy_from_slice(&reply[at..at + 8]);
+            let ntp = crate::NtpTimestamp::from_bytes(bytes);
+            if ntp == crate::NtpTimestamp::new(0, 0) {
+                return None;
+            }
+
+            ntp.to_utc_near(received)
+        };
+        let server_received = timestamp(32).ok_or(SntpError::InvalidResponse)?;
+        let server_time = timestamp(40).ok_or(SntpError::InvalidResponse)?;
+
+        // t1 to t4 as in RFC 4330.
+        let t1 = sent.nanos_since_epoch();
+        let t2 = server_received.nanos_since_epoch();
+        let t3 = server_time.nanos_since_epoch();
+        let t4 = received.nanos_since_epoch();
+        let delay = ((t4 - t1) - (t3 - t2)).max(0);
+        let offset = ((t2 - t1) + (t3 - t4)) / 2;
+
+        let short = |at: usize| crate::NtpShort::from_bytes(
+            [reply[at], reply[at + 1], reply[at + 2], reply[at + 3]]
+        );
+        Ok(Self {
+            server_time,
+            delay: core::time::Duration::new(
+                (delay / 1_000_000_000) as u64,
+                (delay % 1_000_000_000) as u32,
+            ),
+            offset: offset as i64,
+            stratum,
+            root_delay: short(4),
+            root_dispersion: short(8),
+        })
+    }
+
+    /// Gets the server's time when it sent the reply.
+    pub fn server_time(&self) -> crate::Utc {
+        self.server_time
+    }
+
+    /// Gets the time the request and reply spent travelling, not counting
+    /// the time the server took to reply.
+    pub fn delay(&self) -> core::time::Duration {
+        self.delay
+    }
+
+    /// Gets the estimated number of nanoseconds the local clock is behind
+    /// the server's, negative if it is ahead.
+    pub fn offset_nanos(&self) -> i64 {
+        self.offset
+    }
+
+    /// Gets the server's stratum, 1 for a server with a reference clock.
+    pub fn stratum(&self) -> u8 {
+        self.stratum
+    }
+
+    /// Gets the server's round-trip delay to its reference clock.
+    pub fn root_delay(&self) -> core::time::Duration {
+        self.root_delay.to_duration()
+    }
+
+    /// Gets the server's estimate of its error relative to its reference
+    /// clock.
+    pub fn root_dispersion(&self) -> core::time::Duration {
+        self.root_dispersion.to_duration()
+    }
+
+    /// Gets a clock which corrects another clock by the offset.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use utc::{ Clock, SntpClient, SystemClock, };
+    ///
+    /// let response = SntpClient::new("pool.ntp.org:123").query().unwrap();
+    /// let clock = response.corrected(SystemClock);
+    /// println!("{}", clock.now());
+    /// ```
+    pub fn corrected<C: crate::Clock>(&self, clock: C) -> crate::OffsetClock<C> {
+        let offset = core::time::Duration::from_nanos(self.offset.unsigned_abs());
+        if self.offset < 0 {
+            crate::OffsetClock::behind(clock, offset)
+        } else {
+            crate::OffsetClock::ahead(clock, offset)
+        }
+    }
+}
+
+/// An error encountered while querying an SNTP server.
+#[derive(Debug)]
+pub enum SntpError {
+    /// The server's address could not be resolved or the request sent.
+    Io(std::io::Error),
+    /// The server did not reply in time.
+    Timeout,
+    /// The server sent a kiss-of-death with the code, e.g. `DENY` or
+    /// `RATE`, and should not be queried again soon or at all.
+    KissOfDeath([u8; 4]),
+    /// The server's clock is not synchronised.
+    Unsynchronized,
+    /// The reply was not a valid reply to the request.
+    InvalidResponse,
+}
+
+impl core::fmt::Display for SntpError {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Io(error) => write!(fmtr, "could not query server: {error}"),
+            Self::Timeout => fmtr.write_str("timed out waiting for the server"),
+            Self::KissOfDeath(code) => write!(
+                fmtr, "server sent kiss-of-death {}", alloc::string::String::from_utf8_lossy(code)
+            ),
+            Self::Unsynchronized => fmtr.write_str("server is not synchronized"),
+            Self::InvalidResponse => fmtr.write_str("invalid reply from server"),
+        }
+    }
+}
+
+impl core::error::Error for SntpError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,5 @@
-pub(crate) const EPOCH_Y: usize = 1970;
-pub(crate) const EPOCH_M: usize = 1;
-pub(crate) const EPOCH_D: usize = 1;
+pub(crate) const MIN_Y: usize = 1;
+pub(crate) const MAX_Y: usize = 9999;
 
 pub(crate) const DIW: usize = 7;
 
@@ -52,6 +51,8 @@ pub(crate) const M: [&str; 12] = [
     DEC_N,
 ];
 
+// `is_multiple_of` would need Rust 1.87.
+#[allow(clippy::manual_is_multiple_of)]
 pub(crate) const fn ly(year: usize) -> usize {
     (year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)) as usize
 }
@@ -93,3 +94,65 @@ pub(crate) const DIM: [[usize; 2]; 12] = [
     [DEC_D, DEC_D],
 ];
 
+
+/// Gets the number of days from the unix epoch to the date in the proleptic
+/// Gregorian calendar, negative for dates before the epoch.
+pub(crate) const fn days_from_civil(year: i64, month: usize, day: usize) -> i64 {
+    // Count from March so that the leap day is the last day of the year.
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % MIY) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 +
+        day_of_year as i64;
+
+    // 719,468 days from 0000-03-01 to 1970-01-01.
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Gets the year, month and day in the proleptic Gregorian calendar a
+/// number of days from the unix epoch, the inverse of [`days_from_civil`].
+pub(crate) const fn civil_from_days(days: i64) -> (i64, usize, usize) {
+    // Count from 0000-03-01 so that the leap day is the last day of the year.
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 -
+        day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (year_of_era * 365 + year_of_era / 4 - year_of_era / 100);
+    let month_from_march = (day_of_year * 5 + 2) / 153;
+    let day = (day_of_year - (month_from_march * 153 + 2) / 5 + 1) as usize;
+    let month = (month_from_march + 2) as usize % MIY + 1;
+    let year = era * 400 + year_of_era + (month <= 2) as i64;
+    (year, month, day)
+}
+
+/// Rounds down to a whole number, as `f64::floor` does but without `std`.
+pub(crate) const fn floor(x: f64) -> f64 {
+    // Floats this large are already whole, and NaN stays NaN.
+    if !(x > -4_503_599_627_370_496_f64 && x < 4_503_599_627_370_496_f64) {
+        return x;
+    }
+
+    let whole = x as i64 as f64;
+    if whole > x { whole - 1_f64 } else { whole }
+}
+
+/// Gets the cosine of an angle between 0 and π, as `f64::cos` does but
+/// without `std`.
+pub(crate) const fn cos(x: f64) -> f64 {
+    // The Taylor series' terms are below 1e-17 well before the 30th at π.
+    let (mut sum, mut term, mut n) = (1_f64, 1_f64, 1_f64);
+    while n < 60_f64 {
+        term = -term * x * x / (n * (n + 1_f64));
+        sum += term;
+        n += 2_f64;
+    }
+    sum
+}
+
+/// Rounds to the nearest whole number with halves rounded up, without `std`.
+pub(crate) const fn round(x: f64) -> f64 {
+    let whole = floor(x);
+    if x - whole >= 0.5_f64 { whole + 1_f64 } else { whole }
+}
@@ -0,0 +1,387 @@
+/// The label of the second starting at 1970-01-01T00:00:00 TAI.
+const EPOCH_LABEL: i128 = 1 << 62;
+
+/// Gets the label and nanoseconds of a TAI instant.
+const fn parts(tai: crate::Tai) -> (u64, u32) {
+    let nanos = tai.nanos_since_epoch();
+    (
+        (EPOCH_LABEL + nanos.div_euclid(1_000_000_000)) as u64,
+        nanos.rem_euclid(1_000_000_000) as u32,
+    )
+}
+
+/// Gets the TAI instant of a label and nanoseconds.
+const fn tai(label: u64, nanos: u32) -> crate::Tai {
+    crate::Tai::from_nanos_since_epoch(
+        (label as i128 - EPOCH_LABEL) * 1_000_000_000 + nanos as i128
+    )
+}
+
+/// Reads a big-endian number of up to 8 bytes.
+fn read(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |n, b| n << 8 | *b as u64)
+}
+
+/// Writes the bytes of a label as `@` followed by lower-case hex.
+fn fmt_hex(bytes: &[u8], fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+    fmtr.write_str("@")?;
+    bytes.iter().try_for_each(|b| write!(fmtr, "{b:02x}"))
+}
+
+/// Parses `@` followed by the hex of a label, in either case.
+fn parse_hex<const N: usize>(s: &str) -> Result<[u8; N], ParseTai64Error> {
+    let hex = s.strip_prefix('@').ok_or(ParseTai64Error)?.as_bytes();
+    if hex.len() != N * 2 {
+        return Err(ParseTai64Error);
+    }
+
+    let digit = |b: u8| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        b'a'..=b'f' => Ok(b - b'a' + 10),
+        b'A'..=b'F' => Ok(b - b'A' + 10),
+        _ => Err(ParseTai64Error),
+    };
+    let mut bytes = [0; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks(2)) {
+        *byte = digit(pair[0])? << 4 | digit(pair[1])?;
+    }
+
+    Ok(bytes)
+}
+
+/// A TAI64 label: a second of TAI, counted from 2⁶² at the start of 1970
+/// and back from it before.
+///
+/// As TAI is ahead of UTC by the leap seconds, labels converted with the
+/// [embedded](crate::LeapSeconds::embedded) table are ahead of unix time by
+/// more than the 10 seconds of older tools which take the system clock to
+/// be TAI − 10 s. Converting with a table holding only the 1972 entry,
+/// `LeapSeconds::parse("2272060800 10")`, matches those tools.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Tai64, Utc, };
+///
+/// let label = Tai64::from_utc(&Utc::from_ymd(2024, 1, 9));
+/// assert_eq!("@40000000659c8ca5", label.to_string());
+/// assert_eq!(Ok(label), "@40000000659c8ca5".parse());
+/// assert_eq!(Some(label), Tai64::from_bytes(label.to_bytes()));
+/// assert_eq!(Some(Utc::from_ymd(2024, 1, 9)), label.to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tai64 {
+    label: u64,
+}
+
+impl Tai64 {
+    /// Gets the label of the second a TAI instant is in.
+    pub const fn from_tai(tai: crate::Tai) -> Self {
+        Self { label: parts(tai).0, }
+    }
+
+    /// Gets the TAI instant the labelled second starts at.
+    pub const fn to_tai(&self) -> crate::Tai {
+        tai(self.label, 0)
+    }
+
+    /// Gets the label of the second a UTC date/time is in.
+    pub fn from_utc(dt: &crate::Utc) -> Self {
+        Self::from_tai(crate::Tai::from_utc(dt))
+    }
+
+    /// Gets the label of the second a UTC date/time is in using a leap
+    /// second table.
+    pub fn from_utc_with(dt: &crate::Utc, leap_seconds: &crate::LeapSeconds) -> Self {
+        Self::from_tai(crate::Tai::from_utc_with(dt, leap_seconds))
+    }
+
+    /// Gets the UTC date/time the labelled second starts at, or `None` if
+    /// it is out of range.
+    pub fn to_utc(&self) -> Option<crate::Utc> {
+        self.to_tai().to_utc()
+    }
+
+    /// Gets the UTC date/time the labelled second starts at using a leap
+    /// second table.
+    pub fn to_utc_with(&self, leap_seconds: &crate::LeapSeconds) -> Option<crate::Utc> {
+        self.to_tai().to_utc_with(leap_seconds)
+    }
+
+    /// Gets the label as a number.
+    pub const fn label(&self) -> u64 {
+        self.label
+    }
+
+    /// Encodes the label in its 8 byte external form.
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.label.to_be_bytes()
+    }
+
+    /// Decodes a label from its 8 byte external form, or `None` if it is
+    /// not a valid label.
+    pub fn from_bytes(bytes: [u8; 8]) -> Option<Self> {
+        let label = u64::from_be_bytes(bytes);
+        (label >> 63 == 0).then_some(Self { label, })
+    }
+}
+
+impl From<crate::Tai> for Tai64 {
+    fn from(tai: crate::Tai) -> Self {
+        Self::from_tai(tai)
+    }
+}
+
+/// Formats as `@` followed by the 16 hex digits of the external form.
+impl core::fmt::Display for Tai64 {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_hex(&self.to_bytes(), fmtr)
+    }
+}
+
+impl core::str::FromStr for Tai64 {
+    type Err = ParseTai64Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(parse_hex(s)?).ok_or(ParseTai64Error)
+    }
+}
+
+/// A TAI64N label: a [`Tai64`] label and a number of nanoseconds into the
+/// second, as used by daemontools' `multilog` and `tai64n`.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Tai64N, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let label = Tai64N::from_utc(&dt);
+/// assert_eq!("@40000000659d72911dcd6500", label.to_string());
+/// assert_eq!(Ok(label), "@40000000659d72911dcd6500".parse());
+/// assert_eq!(Some(dt), label.to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tai64N {
+    label: u64,
+    nanos: u32,
+}
+
+impl Tai64N {
+    /// Gets the label of a TAI instant.
+    pub const fn from_tai(tai: crate::Tai) -> Self {
+        let (label, nanos) = parts(tai);
+        Self { label, nanos, }
+    }
+
+    /// Gets the labelled TAI instant.
+    pub const fn to_tai(&self) -> crate::Tai {
+        tai(self.label, self.nanos)
+    }
+
+    /// Gets the label of a UTC date/time.
+    pub fn from_utc(dt: &crate::Utc) -> Self {
+        Self::from_tai(crate::Tai::from_utc(dt))
+    }
+
+    /// Gets the label of a UTC date/time using a leap second table.
+    pub fn from_utc_with(dt: &crate::Utc, leap_seconds: &crate::LeapSeconds) -> Self {
+        Self::from_tai(crate::Tai::from_utc_with(dt, leap_seconds))
+    }
+
+    /// Gets the labelled UTC date/time, or `None` if it is out of range.
+    pub fn to_utc(&self) -> Option<crate::Utc> {
+        self.to_tai().to_utc()
+    }
+
+    /// Gets the labelled UTC date/time using a leap second table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, Tai64N, Utc, };
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// // Logged by `multilog`, which labels the system clock + 10 s.
+    /// let label: Tai64N = "@40000000659d7276001e8480".parse().unwrap();
+    /// let daemontools = LeapSeconds::parse("2272060800 10").unwrap();
+    /// assert_eq!(
+    ///     Some(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.002_f64)),
+    ///     label.to_utc_with(&daemontools)
+    /// );
+    /// # }
+    /// ```
+    pub fn to_utc_with(&self, leap_seconds: &crate::LeapSeconds) -> Option<crate::Utc> {
+        self.to_tai().to_utc_with(leap_seconds)
+    }
+
+    /// Gets the [`Tai64`] label of the second.
+    pub const fn tai64(&self) -> Tai64 {
+        Tai64 { label: self.label, }
+    }
+
+    /// Gets the number of nanoseconds into the second.
+    pub const fn nanos(&self) -> u32 {
+        self.nanos
+    }
+
+    /// Encodes the label in its 12 byte external form.
+    pub const fn to_bytes(&self) -> [u8; 12] {
+        let mut bytes = [0; 12];
+        let (label, nanos) = (self.label.to_be_bytes(), self.nanos.to_be_bytes());
+        let mut i = 0;
+        while i < 12 {
+            bytes[i] = if i < 8 { label[i] } else { nanos[i - 8] };
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Decodes a label from its 12 byte external form, or `None` if it is
+    /// not a valid label.
+    pub fn from_bytes(bytes: [u8; 12]) -> Option<Self> {
+        let (label, nanos) = (read(&bytes[..8]), read(&bytes[8..]) as u32);
+        (label >> 63 == 0 && nanos < 1_000_000_000).then_some(Self { label, nanos, })
+    }
+}
+
+impl From<crate::Tai> for Tai64N {
+    fn from(tai: crate::Tai) -> Self {
+        Self::from_tai(tai)
+    }
+}
+
+/// Formats as `@` followed by the 24 hex digits of the external form.
+impl core::fmt::Display for Tai64N {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_hex(&self.to_bytes(), fmtr)
+    }
+}
+
+impl core::str::FromStr for Tai64N {
+    type Err = ParseTai64Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(parse_hex(s)?).ok_or(ParseTai64Error)
+    }
+}
+
+/// A TAI64NA label: a [`Tai64N`] label and a number of attoseconds into the
+/// nanosecond.
+///
+/// Attoseconds are dropped when converting to TAI or UTC.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Tai64NA, Utc, };
+///
+/// let label: Tai64NA = "@40000000659d72911dcd6500000003e8".parse().unwrap();
+/// assert_eq!(1_000, label.attos());
+/// assert_eq!(Some(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64)), label.to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tai64NA {
+    label: u64,
+    nanos: u32,
+    attos: u32,
+}
+
+impl Tai64NA {
+    /// Gets the label of a TAI instant.
+    pub const fn from_tai(tai: crate::Tai) -> Self {
+        let (label, nanos) = parts(tai);
+        Self { label, nanos, attos: 0, }
+    }
+
+    /// Gets the labelled TAI instant.
+    pub const fn to_tai(&self) -> crate::Tai {
+        tai(self.label, self.nanos)
+    }
+
+    /// Gets the label of a UTC date/time.
+    pub fn from_utc(dt: &crate::Utc) -> Self {
+        Self::from_tai(crate::Tai::from_utc(dt))
+    }
+
+    /// Gets the label of a UTC date/time using a leap second table.
+    pub fn from_utc_with(dt: &crate::Utc, leap_seconds: &crate::LeapSeconds) -> Self {
+        Self::from_tai(crate::Tai::from_utc_with(dt, leap_seconds))
+    }
+
+    /// Gets the labelled UTC date/time, or `None` if it is out of range.
+    pub fn to_utc(&self) -> Option<crate::Utc> {
+        self.to_tai().to_utc()
+    }
+
+    /// Gets the labelled UTC date/time using a leap second table.
+    pub fn to_utc_with(&self, leap_seconds: &crate::LeapSeconds) -> Option<crate::Utc> {
+        self.to_tai().to_utc_with(leap_seconds)
+    }
+
+    /// Gets the [`Tai64N`] label of the nanosecond.
+    pub const fn tai64n(&self) -> Tai64N {
+        Tai64N { label: self.label, nanos: self.nanos, }
+    }
+
+    /// Gets the number of attoseconds into the nanosecond.
+    pub const fn attos(&self) -> u32 {
+        self.attos
+    }
+
+    /// Encodes the label in its 16 byte external form.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        let (nanos, attos) = (self.tai64n().to_bytes(), self.attos.to_be_bytes());
+        let mut i = 0;
+        while i < 16 {
+            bytes[i] = if i < 12 { nanos[i] } else { attos[i - 12] };
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Decodes a label from its 16 byte external form, or `None` if it is
+    /// not a valid label.
+    pub fn from_bytes(bytes: [u8; 16]) -> Option<Self> {
+        let mut nanos = [0; 12];
+        nanos.copy_from_slice(&bytes[..12]);
+        let Tai64N { label, nanos, } = Tai64N::from_bytes(nanos)?;
+        let attos = read(&bytes[12..]) as u32;
+        (attos < 1_000_000_000).then_some(Self { label, nanos, attos, })
+    }
+}
+
+impl From<crate::Tai> for Tai64NA {
+    fn from(tai: crate::Tai) -> Self {
+        Self::from_tai(tai)
+    }
+}
+
+/// Formats as `@` followed by the 32 hex digits of the external form.
+impl core::fmt::Display for Tai64NA {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_hex(&self.to_bytes(), fmtr)
+    }
+}
+
+impl core::str::FromStr for Tai64NA {
+    type Err = ParseTai64Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(parse_hex(s)?).ok_or(ParseTai64Error)
+    }
+}
+
+/// An error encountered while parsing a [`Tai64`], [`Tai64N`] or
+/// [`Tai64NA`] label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseTai64Error;
+
+impl core::fmt::Display for ParseTai64Error {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmtr.write_str("invalid TAI64 label")
+    }
+}
+
+impl core::error::Error for ParseTai64Error {}
@@ -1,100 +1,119 @@
+#[cfg(feature = "std")]
 #[test]
 fn now() {
     crate::Utc::now();
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn ord_1() {
-    let a = crate::Utc::now();
-    let b = crate::Utc::now();
+    let clock = crate::MonotonicUtc::new();
+    let a = crate::Clock::now(&clock);
+    let b = crate::Clock::now(&clock);
     assert!(a < b);
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn ord_2() {
-    let a = crate::Utc::now();
-    let b = crate::Utc::now();
+    let clock = crate::MonotonicUtc::new();
+    let a = crate::Clock::now(&clock);
+    let b = crate::Clock::now(&clock);
     assert!(b > a);
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn standard_date() {
     let dt = crate::Utc::from_ymdhms(2024, 1, 5, 12, 0, 0_f64);
     assert_eq!("2024-01-05T12:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn on_leap_day() {
     let dt = crate::Utc::from_ymdhms(2000, 2, 29, 12, 0, 0_f64);
     assert_eq!("2000-02-29T12:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn on_leap_day_non_leap_year() {
     let dt = crate::Utc::from_ymdhms(2001, 2, 29, 12, 0, 0_f64);
     assert_eq!("2001-03-01T12:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn underflow_month() {
     let dt = crate::Utc::from_ymd(2020, 0, 1);
     assert_eq!("2019-12-01T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn underflow_day() {
     let dt = crate::Utc::from_ymd(2020, 1, 0);
     assert_eq!("2019-12-31T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn underflow_month_and_day() {
     let dt = crate::Utc::from_ymd(2020, 0, 0);
     assert_eq!("2019-11-30T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_month() {
     let dt = crate::Utc::from_ymd(2020, 13, 1);
     assert_eq!("2021-01-01T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_day() {
     let dt = crate::Utc::from_ymd(2020, 12, 32);
     assert_eq!("2021-01-01T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_day_and_month() {
     let dt = crate::Utc::from_ymd(2020, 13, 32);
     assert_eq!("2021-02-01T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_hour() {
     let dt = crate::Utc::from_ymdhms(2018, 11, 11, 24, 0, 0_f64);
     assert_eq!("2018-11-12T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_hour_and_day() {
     let dt = crate::Utc::from_ymdhms(2018, 11, 31, 24, 0, 0_f64);
     assert_eq!("2018-12-02T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_hour_day_and_month() {
     let dt = crate::Utc::from_ymdhms(2018, 13, 32, 24, 0, 0_f64);
     assert_eq!("2019-02-02T00:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_minute() {
     let dt = crate::Utc::from_ymdhms(2017, 12, 10, 12, 60, 0_f64);
     assert_eq!("2017-12-10T13:00:00", dt.as_rfc3339());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn overflow_second() {
     let dt = crate::Utc::from_ymdhms(2017, 12, 10, 12, 60, 0_f64);
@@ -155,6 +174,7 @@ fn month_of_year() {
     assert_eq!("January", dt.month_of_year());
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn month_of_year_one_month_after_epoch() {
     let dt = crate::Utc::from_ymd(2024, 2, 1);
@@ -162,36 +182,59 @@ fn month_of_year_one_month_after_epoch() {
     assert_eq!("February", dt.month_of_year());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn with_nanos_2_precision() {
     let dt = crate::Utc::from_ymdhms(2024, 1, 5, 5, 34, 12.324_f64);
     assert_eq!("2024-01-05T05:34:12.32", dt.as_rfc3339_with_nano(2));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn rfc7231() {
     let dt = crate::Utc::from_ymdhms(2024, 1, 5, 11, 44, 58.0_f64);
     assert_eq!("Fri, 05 Jan 2024 11:44:58 GMT", dt.as_rfc7231());
 }
 
+#[cfg(feature = "alloc")]
 #[test]
-#[should_panic]
 fn before_epoch() {
-    crate::Utc::from_ymdhms(1969, 12, 31, 11, 59, 59.99_f64);
+    let dt = crate::Utc::from_ymdhms(1969, 12, 31, 11, 59, 59.25_f64);
+    assert_eq!("1969-12-31T11:59:59.250000", dt.as_rfc3339_nano());
+    assert_eq!("Wednesday", dt.day_of_week());
+    assert_eq!("1969-12-31T00:00:00", crate::Utc::from_ymd(1970, 0, 31).as_rfc3339());
+    assert_eq!("1969-12-31T00:00:00", crate::Utc::from_ymd(1970, 1, 0).as_rfc3339());
+    assert_eq!("1601-01-01T00:00:00", crate::Utc::from_ymd(1601, 1, 1).as_rfc3339());
+    assert_eq!("Monday", crate::Utc::from_ymd(1601, 1, 1).day_of_week());
+    assert_eq!("0001-01-01T00:00:00", crate::Utc::from_ymd(1, 1, 1).as_rfc3339());
 }
 
+#[cfg(feature = "std")]
 #[test]
-#[should_panic]
-fn before_epoch_underflow_month() {
-    crate::Utc::from_ymd(1970, 0, 31);
+fn all_days_before_epoch() {
+    let mut dt = crate::Utc::epoch();
+    for _ in 0..800 {
+        let earlier = dt - std::time::Duration::from_secs(86_400);
+        assert_eq!(
+            earlier,
+            crate::Utc::from_ymd(dt.year(), dt.month(), dt.day() - 1)
+        );
+        assert_eq!(
+            crate::calendar::weekday_of(earlier.year(), earlier.month(), earlier.day()).name(),
+            earlier.day_of_week()
+        );
+        dt = earlier;
+    }
+    assert_eq!("1967-10-24T00:00:00", dt.as_rfc3339());
 }
 
 #[test]
 #[should_panic]
-fn before_epoch_underflow_day() {
-    crate::Utc::from_ymd(1970, 1, 0);
+fn before_min() {
+    crate::Utc::from_ymd(1, 1, 0);
 }
 
+#[cfg(feature = "std")]
 #[test]
 fn all_days_since_epoch() {
     let start = crate::Utc::epoch();
@@ -210,3 +253,1809 @@ fn all_days_since_epoch() {
     }
 }
 
+
+#[cfg(feature = "std")]
+#[test]
+fn human_duration_compact() {
+    let d = std::time::Duration::new(93_784, 5_000_000);
+    assert_eq!("1d 2h 3m 4s 5ms", crate::HumanDuration::new(d).to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn human_duration_long_singular() {
+    let d = std::time::Duration::from_secs(86_400 + 60);
+    let h = crate::HumanDuration::new(d).style(crate::DurationStyle::Long);
+    assert_eq!("1 day, 1 minute", h.to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn human_duration_largest_units_skips_zeros() {
+    let d = std::time::Duration::from_secs(3_605);
+    let h = crate::HumanDuration::new(d).largest_units(2);
+    assert_eq!("1h", h.to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn human_duration_zero() {
+    let h = crate::HumanDuration::new(std::time::Duration::ZERO);
+    assert_eq!("0s", h.to_string());
+    assert_eq!("0 seconds", h.style(crate::DurationStyle::Long).to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn human_duration_sub_second() {
+    let d = std::time::Duration::from_nanos(1_500);
+    assert_eq!("1us 500ns", crate::HumanDuration::new(d).to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn human_duration_round_trip() {
+    let d = std::time::Duration::new(1_234_567, 891_011_121);
+    for style in [crate::DurationStyle::Compact, crate::DurationStyle::Long] {
+        let s = crate::HumanDuration::new(d).style(style).to_string();
+        assert_eq!(d, crate::parse_duration(&s).unwrap());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_duration_fractional() {
+    let d = crate::parse_duration("1.25s").unwrap();
+    assert_eq!(std::time::Duration::from_millis(1_250), d);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_duration_weeks_and_spelled_units() {
+    let d = crate::parse_duration("1 week 2 hrs, 30 mins").unwrap();
+    assert_eq!(std::time::Duration::from_secs(604_800 + 9_000), d);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn parse_duration_errors() {
+    assert_eq!(Err(crate::ParseDurationError::Empty), crate::parse_duration(" "));
+    assert_eq!(Err(crate::ParseDurationError::MissingUnit(2)), crate::parse_duration("90"));
+    assert_eq!(Err(crate::ParseDurationError::InvalidNumber(3)), crate::parse_duration("1h m"));
+    assert_eq!(
+        Err(crate::ParseDurationError::UnknownUnit(2, "fortnights".to_string())),
+        crate::parse_duration("3 fortnights")
+    );
+    assert_eq!(
+        Err(crate::ParseDurationError::Overflow),
+        crate::parse_duration("99999999999999999999w")
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn duration_since() {
+    let a = crate::Utc::from_ymdhms(2023, 12, 31, 23, 59, 59.5_f64);
+    let b = crate::Utc::from_ymdhms(2024, 1, 1, 0, 0, 0.25_f64);
+    assert_eq!(Some(std::time::Duration::from_millis(750)), b.duration_since(&a));
+    assert_eq!(None, a.duration_since(&b));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn relative_just_now() {
+    let now = crate::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64);
+    let then = crate::Utc::from_ymdhms(2024, 1, 9, 11, 59, 55_f64);
+    assert_eq!("just now", then.relative_to(&now));
+    assert_eq!("5 seconds ago", crate::RelativeFormat::new()
+        .just_now(std::time::Duration::ZERO)
+        .format(&then, &now));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn relative_minutes_and_hours() {
+    let now = crate::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64);
+    let a = crate::Utc::from_ymdhms(2024, 1, 9, 11, 59, 0_f64);
+    let b = crate::Utc::from_ymdhms(2024, 1, 9, 14, 30, 0_f64);
+    assert_eq!("1 minute ago", a.relative_to(&now));
+    assert_eq!("in 2 hours", b.relative_to(&now));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn relative_hours_across_midnight() {
+    let now = crate::Utc::from_ymdhms(2024, 1, 9, 2, 0, 0_f64);
+    let then = crate::Utc::from_ymdhms(2024, 1, 8, 23, 0, 0_f64);
+    assert_eq!("3 hours ago", then.relative_to(&now));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn relative_weekdays() {
+    let now = crate::Utc::from_ymd(2024, 1, 9);
+    let then = crate::Utc::from_ymd(2024, 1, 12);
+    assert_eq!("next Friday", then.relative_to(&now));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn relative_months_and_years() {
+    let now = crate::Utc::from_ymd(2024, 3, 15);
+    assert_eq!("2 months ago", crate::Utc::from_ymd(2024, 1, 10).relative_to(&now));
+    assert_eq!("1 month ago", crate::Utc::from_ymd(2024, 1, 20).relative_to(&now));
+    assert_eq!("in 2 years", crate::Utc::from_ymd(2026, 5, 1).relative_to(&now));
+
+    // A month less four hours is not yet a whole month.
+    let now = crate::Utc::from_ymdhms(2024, 2, 9, 8, 0, 0_f64);
+    let then = crate::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64);
+    assert_eq!("4 weeks ago", then.relative_to(&now));
+    let then = crate::Utc::from_ymdhms(2024, 1, 9, 8, 0, 0_f64);
+    assert_eq!("1 month ago", then.relative_to(&now));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn relative_thresholds() {
+    let hours = |hours: u64| std::time::Duration::from_secs(hours * 3_600);
+    let now = crate::Utc::from_ymdhms(2024, 3, 15, 12, 0, 0_f64);
+    let fmt = crate::RelativeFormat::new();
+
+    let then = crate::Utc::from_ymdhms(2024, 3, 15, 10, 30, 0_f64);
+    assert_eq!("1 hour ago", fmt.format(&then, &now));
+    assert_eq!("90 minutes ago", fmt.hour(hours(2)).format(&then, &now));
+
+    let then = crate::Utc::from_ymdhms(2024, 3, 14, 20, 0, 0_f64);
+    assert_eq!("16 hours ago", fmt.day(hours(24)).format(&then, &now));
+    assert_eq!("yesterday", fmt.format(&then, &now));
+
+    let then = crate::Utc::from_ymd(2024, 3, 5);
+    assert_eq!("1 week ago", fmt.format(&then, &now));
+    assert_eq!("10 days ago", fmt.week(hours(14 * 24)).format(&then, &now));
+
+    let then = crate::Utc::from_ymd(2024, 1, 30);
+    assert_eq!("1 month ago", fmt.format(&then, &now));
+    assert_eq!("6 weeks ago", fmt.month(hours(60 * 24)).format(&then, &now));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn natural_days_and_times() {
+    let now = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    let parse = |s| crate::parse_natural_at(s, &now).unwrap().start();
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 9), parse("today"));
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 8), parse("Yesterday"));
+    assert_eq!(crate::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64), parse("noon"));
+    assert_eq!(crate::Utc::from_ymdhms(2024, 1, 10, 21, 30, 0_f64), parse("9:30 pm tomorrow"));
+    assert_eq!(crate::Utc::from_ymdhms(2024, 1, 12, 17, 0, 0_f64), parse("friday at 17:00"));
+    assert_eq!(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 0_f64), parse("12am"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn natural_weekdays() {
+    // 2024-01-09 was a Tuesday.
+    let now = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    let parse = |s| crate::parse_natural_at(s, &now).unwrap().start();
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 9), parse("this tuesday"));
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 16), parse("next tue"));
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 2), parse("last tuesday"));
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 5), parse("last friday"));
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 15), parse("monday"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn natural_offsets() {
+    let now = crate::Utc::from_ymdhms(2024, 3, 31, 8, 0, 0_f64);
+    let parse = |s| crate::parse_natural_at(s, &now).unwrap().start();
+    assert_eq!(crate::Utc::from_ymdhms(2024, 3, 28, 8, 0, 0_f64), parse("3 days ago"));
+    assert_eq!(crate::Utc::from_ymdhms(2024, 3, 31, 9, 0, 0_f64), parse("in an hour"));
+    assert_eq!(crate::Utc::from_ymdhms(2024, 2, 29, 8, 0, 0_f64), parse("one month ago"));
+    assert_eq!(crate::Utc::from_ymdhms(2025, 3, 31, 8, 0, 0_f64), parse("1 year from now"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn natural_intervals() {
+    let now = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    assert_eq!(
+        crate::NaturalDate::Interval(
+            crate::Utc::from_ymd(2024, 1, 15),
+            crate::Utc::from_ymd(2024, 1, 22),
+        ),
+        crate::parse_natural_at("next week", &now).unwrap()
+    );
+    assert_eq!(
+        crate::NaturalDate::Interval(
+            crate::Utc::from_ymd(2023, 1, 1),
+            crate::Utc::from_ymd(2024, 1, 1),
+        ),
+        crate::parse_natural_at("last year", &now).unwrap()
+    );
+    assert_eq!(
+        crate::NaturalDate::Instant(crate::Utc::from_ymd(2024, 1, 1)),
+        crate::parse_natural_at("first day of this month", &now).unwrap()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn natural_errors() {
+    let now = crate::Utc::from_ymd(2024, 1, 9);
+    assert_eq!(
+        Err(crate::ParseNaturalError::UnexpectedEnd { expected: "a date expression", }),
+        crate::parse_natural_at("", &now)
+    );
+    assert_eq!(
+        Err(crate::ParseNaturalError::UnexpectedToken {
+            position: 9,
+            token: "25:00".to_string(),
+            expected: "a time of day",
+        }),
+        crate::parse_natural_at("tomorrow 25:00", &now)
+    );
+    assert_eq!(
+        Err(crate::ParseNaturalError::UnexpectedToken {
+            position: 8,
+            token: "later".to_string(),
+            expected: "\"ago\" or \"from now\"",
+        }),
+        crate::parse_natural_at("2 weeks later", &now)
+    );
+    assert_eq!(
+        Err(crate::ParseNaturalError::OutOfRange),
+        crate::parse_natural_at("2024 years ago", &now)
+    );
+    assert_eq!(
+        Err(crate::ParseNaturalError::OutOfRange),
+        crate::parse_natural_at("10000 years from now", &now)
+    );
+    let late = crate::Utc::from_ymd(9999, 6, 1);
+    assert_eq!(
+        Err(crate::ParseNaturalError::OutOfRange),
+        crate::parse_natural_at("next year", &late)
+    );
+    assert_eq!(
+        Err(crate::ParseNaturalError::OutOfRange),
+        crate::parse_natural_at("7 months from now", &late)
+    );
+    assert_eq!(
+        Ok(crate::NaturalDate::Instant(crate::Utc::from_ymd(9999, 12, 1))),
+        crate::parse_natural_at("6 months from now", &late)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_any_rfc3339_fraction_and_offset() {
+    let dt = crate::Utc::parse_any(
+        "2024-01-01T01:30:00.123456789-02:30", &crate::ParseHints::new()
+    ).unwrap();
+    assert_eq!("2024-01-01T04:00:00.123456", dt.as_rfc3339_nano());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_any_own_formats() {
+    let hints = crate::ParseHints::new();
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.25_f64);
+    for s in [dt.as_rfc3339_nano(), dt.as_iso8601(), dt.as_ymd(), dt.as_rfc7231()] {
+        assert!(crate::Utc::parse_any(&s, &hints).is_ok(), "{s}");
+    }
+    assert_eq!(dt, crate::Utc::parse_any(&dt.as_iso8601(), &hints).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_any_named_month() {
+    let hints = crate::ParseHints::new();
+    let expected = crate::Utc::from_ymdhms(2024, 1, 9, 8, 30, 0_f64);
+    assert_eq!(expected, crate::Utc::parse_any("January 9, 2024 08:30", &hints).unwrap());
+    assert_eq!(expected, crate::Utc::parse_any("09 jan 2024 at 08:30", &hints).unwrap());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_any_numeric_dates() {
+    let hints = crate::ParseHints::new();
+    assert_eq!(
+        crate::Utc::from_ymd(2024, 3, 3),
+        crate::Utc::parse_any("3.3.2024", &hints).unwrap()
+    );
+    assert_eq!(
+        crate::Utc::from_ymd(2024, 12, 25),
+        crate::Utc::parse_any("25-12-2024", &hints).unwrap()
+    );
+    let hints = hints.date_order(crate::DateOrder::MonthFirst);
+    assert_eq!(
+        crate::Utc::from_ymd(2024, 1, 9),
+        crate::Utc::parse_any("01/09/2024", &hints).unwrap()
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_any_epoch_units() {
+    let hints = crate::ParseHints::new().epoch_unit(crate::EpochUnit::Millis);
+    let dt = crate::Utc::parse_any("1500.5", &hints).unwrap();
+    assert_eq!("1970-01-01T00:00:01.500500", dt.as_rfc3339_nano());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_any_errors() {
+    let hints = crate::ParseHints::new();
+    assert_eq!(Err(crate::ParseAnyError::Unrecognized), crate::Utc::parse_any("soon", &hints));
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Iso8601)),
+        crate::Utc::parse_any("2023-02-29", &hints)
+    );
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Rfc7231)),
+        crate::Utc::parse_any("Mon, 09 Jan 2024 16:21:00 GMT", &hints)
+    );
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Rfc3339)),
+        crate::Utc::parse_any("0001-01-01T00:30:00+01:00", &hints)
+    );
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Syslog)),
+        crate::Utc::parse_any("Jan  9 16:21:00", &crate::ParseHints::new().year(10000))
+    );
+    assert!(crate::find_timestamps("Jan  9 16:21:00 host", &crate::ParseHints::new().year(0)).is_empty());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn find_timestamps_formats() {
+    let text = "a=2024-01-09T16:21:00Z b=Tue, 09 Jan 2024 16:21:00 GMT \
+        c=2024-01-09 16:21:00.000 d=9 Jan 2024 16:21";
+    let found = crate::find_timestamps(text, &crate::ParseHints::new());
+    let formats = found.iter().map(|f| f.format()).collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            crate::Format::Rfc3339,
+            crate::Format::Rfc7231,
+            crate::Format::Iso8601,
+            crate::Format::NamedMonth,
+        ],
+        formats
+    );
+    let expected = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    assert!(found.iter().all(|f| f.utc() == expected));
+    assert_eq!(&text[found[0].span()], "2024-01-09T16:21:00Z");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn find_timestamps_skips_non_timestamps() {
+    let text = "id 1704817260 on 2024-01-09 at [x] ver 1.2.3 x2024-01-09T16:21:00Z";
+    assert!(crate::find_timestamps(text, &crate::ParseHints::new()).is_empty());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn find_timestamps_skips_ambiguous() {
+    let text = "03/04/2024 10:00 and 13/04/2024 10:00";
+    let found = crate::find_timestamps(text, &crate::ParseHints::new());
+    assert_eq!(1, found.len());
+    assert_eq!(21..37, found[0].span());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn normalize_timestamps_in_text() {
+    let text = "<34>Oct  3 22:14:15 mymachine su: failed at [1704817260123] µs";
+    let hints = crate::ParseHints::new().year(2023);
+    assert_eq!(
+        "<34>2023-10-03T22:14:15.000000 mymachine su: failed at \
+            [2024-01-09T16:21:00.123000] µs",
+        crate::normalize_timestamps(text, &hints)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn normalize_timestamps_leaves_pids_and_counts() {
+    let text = "Jan  9 16:21:00 host sshd[1234]: accepted [200] at [1704817261] _x[1704817262]";
+    let hints = crate::ParseHints::new().year(2024);
+    assert_eq!(
+        "2024-01-09T16:21:00.000000 host sshd[1234]: accepted [200] at \
+            [2024-01-09T16:21:01.000000] _x[1704817262]",
+        crate::normalize_timestamps(text, &hints)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn overflow_seconds_into_minutes() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 1, 0, 0, 120.0_f64);
+    assert_eq!("2024-01-01T00:02:00", dt.as_rfc3339());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn overflow_many_months() {
+    let dt = crate::Utc::from_ymd(2020, 24, 1);
+    assert_eq!("2021-12-01T00:00:00", dt.as_rfc3339());
+    let dt = crate::Utc::from_ymd(2020, 25, 1);
+    assert_eq!("2022-01-01T00:00:00", dt.as_rfc3339());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn overflow_large_components() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 1, 1000, 1000, 100_000.5_f64);
+    assert_eq!("2024-02-13T12:26:40.500000", dt.as_rfc3339_nano());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn underflow_signed_components() {
+    let dt = crate::Utc::from_ymdhms_signed(2024, 1, -45, 0, 0, 0_f64);
+    assert_eq!("2023-11-16T00:00:00", dt.as_rfc3339());
+    let dt = crate::Utc::from_ymdhms_signed(2024, -14, 1, 0, 0, 0_f64);
+    assert_eq!("2022-10-01T00:00:00", dt.as_rfc3339());
+    let dt = crate::Utc::from_ymdhms_signed(2024, 1, 1, -1, 0, 0_f64);
+    assert_eq!("2023-12-31T23:00:00", dt.as_rfc3339());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn before_epoch_signed() {
+    let dt = crate::Utc::from_ymdhms_signed(1970, 1, 1, 0, 0, -0.5_f64);
+    assert_eq!("1969-12-31T23:59:59.500000", dt.as_rfc3339_nano());
+}
+
+#[test]
+#[should_panic]
+fn before_min_signed() {
+    crate::Utc::from_ymdhms_signed(1, 1, 1, 0, 0, -0.5_f64);
+}
+
+#[test]
+fn strict_rejects_out_of_range() {
+    let rejected = |y, mo, d, h, mi, s| crate::Utc::from_ymdhms_strict(y, mo, d, h, mi, s)
+        .unwrap_err()
+        .component();
+    assert_eq!(crate::Component::Year, rejected(0, 1, 1, 0, 0, 0_f64));
+    assert_eq!(crate::Component::Year, rejected(10000, 1, 1, 0, 0, 0_f64));
+    assert_eq!(crate::Component::Month, rejected(2024, 13, 1, 0, 0, 0_f64));
+    assert_eq!(crate::Component::Day, rejected(2024, 4, 31, 0, 0, 0_f64));
+    assert_eq!(crate::Component::Hour, rejected(2024, 1, 1, 24, 0, 0_f64));
+    assert_eq!(crate::Component::Minute, rejected(2024, 1, 1, 0, 60, 0_f64));
+    assert_eq!(crate::Component::Second, rejected(2024, 1, 1, 0, 0, -1_f64));
+    assert_eq!(crate::Component::Second, rejected(2024, 1, 1, 0, 0, f64::NAN));
+}
+
+#[test]
+fn strict_leap_day_non_leap_year() {
+    let err = crate::Utc::from_ymdhms_strict(2001, 2, 29, 12, 0, 0_f64).unwrap_err();
+    assert_eq!(crate::Component::Day, err.component());
+    assert!(crate::Utc::from_ymdhms_strict(2000, 2, 29, 12, 0, 0_f64).is_ok());
+}
+
+#[test]
+fn calendar_days_in_year_matches_months() {
+    for year in [1900, 1970, 2000, 2023, 2024] {
+        let sum = (1..=12).map(|m| crate::calendar::days_in_month(year, m)).sum::<usize>();
+        assert_eq!(crate::calendar::days_in_year(year), sum);
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn calendar_weekday_matches_day_of_week() {
+    let mut dt = crate::Utc::from_ymd(2023, 12, 25);
+    for _ in 0..14 {
+        let weekday = crate::calendar::weekday_of(dt.year(), dt.month(), dt.day());
+        assert_eq!(dt.day_of_week(), weekday.name());
+        assert_eq!(weekday, dt.weekday());
+        dt = dt + std::time::Duration::from_secs(86_400);
+    }
+}
+
+#[test]
+#[should_panic]
+fn calendar_days_in_invalid_month() {
+    crate::calendar::days_in_month(2024, 0);
+}
+
+#[test]
+fn const_constructors() {
+    const LAUNCH: crate::Utc = crate::Utc::from_ymd_const(2024, 2, 29);
+    const YEAR: usize = LAUNCH.year();
+    assert_eq!(2024, YEAR);
+    assert_eq!(crate::Utc::from_ymd(2024, 2, 29), LAUNCH);
+    assert_eq!("Thursday", LAUNCH.day_of_week());
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn const_normalizing_constructors() {
+    const NEW_YEAR: crate::Utc = crate::Utc::from_ymdhms(2023, 12, 31, 24, 0, 0_f64);
+    assert_eq!("2024-01-01T00:00:00", NEW_YEAR.as_rfc3339());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn epoch_min_and_max() {
+    assert_eq!(crate::Utc::epoch(), crate::Utc::EPOCH);
+    assert_eq!("0001-01-01T00:00:00", crate::Utc::MIN.as_rfc3339());
+    assert_eq!("Monday", crate::Utc::MIN.day_of_week());
+    assert_eq!(None, crate::Utc::MIN.checked_sub(std::time::Duration::from_nanos(1)));
+    assert_eq!("9999-12-31T23:59:59", crate::Utc::MAX.as_rfc3339());
+    assert!(crate::Utc::MAX.nano() > 0.999_999_998_f64);
+    assert_eq!("Friday", crate::Utc::MAX.day_of_week());
+    assert_eq!(None, crate::Utc::MAX.checked_add(std::time::Duration::from_nanos(1)));
+}
+
+#[test]
+fn signed_components_cancel_at_extremes() {
+    let dt = crate::Utc::from_ymdhms_signed(
+        100_000_000_000_000_000, -(100_000_000_000_000_000 - 2024) * 12 + 1, 1, 0, 0, 0_f64,
+    );
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 1), dt);
+    let k = i64::MAX / 3_600;
+    let dt = crate::Utc::from_ymdhms_signed(2024, 1, 1, k * 60, -k * 3_600, 0_f64);
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 1), dt);
+}
+
+#[test]
+#[should_panic(expected = "Date must be < 10000")]
+fn signed_year_far_after_max() {
+    crate::Utc::from_ymdhms_signed(i64::MAX, i64::MAX, i64::MAX, i64::MAX, i64::MAX, 1e300_f64);
+}
+
+#[test]
+#[should_panic(expected = "Date must be >= 0001")]
+fn signed_year_far_before_min() {
+    crate::Utc::from_ymdhms_signed(i64::MIN, i64::MIN, i64::MIN, i64::MIN, i64::MIN, -1e300_f64);
+}
+
+#[test]
+#[should_panic(expected = "Date must be < 10000")]
+fn signed_year_half_max() {
+    crate::Utc::from_ymdhms_signed(i64::MAX / 2, 1, 1, 0, 0, 0_f64);
+}
+
+#[test]
+#[should_panic]
+fn after_max() {
+    crate::Utc::from_ymd(10000, 1, 1);
+}
+
+#[test]
+#[should_panic]
+fn const_constructor_rejects_at_runtime() {
+    crate::Utc::from_ymd_const(2023, 2, 29);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fraction_just_below_one_truncates() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 59.9996_f64);
+    assert_eq!("2024-01-09T16:21:59.999", dt.as_rfc3339_with_nano(3));
+    assert_eq!("2024-01-09T16:21:59.999", dt.to_rfc3339_with_nano_buf(3));
+    assert_eq!("2024-01-09 16:21:59.999", dt.as_iso8601());
+    assert_eq!("2024-01-09T16:21:59.999600000000", dt.as_rfc3339_with_nano(12));
+    assert_eq!("2024-01-09T16:21:59.999", format!("{dt:.3}"));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn buffers_match_strings() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 5, 5, 34, 12.324_f64);
+    assert_eq!(dt.as_rfc3339(), dt.to_rfc3339_buf().as_str());
+    assert_eq!(dt.as_ymd(), dt.to_ymd_buf().as_str());
+    assert_eq!(dt.as_rfc7231(), dt.to_rfc7231_buf().as_str());
+    assert_eq!(dt.as_iso8601(), dt.to_iso8601_with_precision_buf(3).as_str());
+    assert_eq!(dt.as_rfc3339_nano(), dt.to_rfc3339_with_nano_buf(6).as_str());
+    assert_eq!("2024-01-05T05:34:12.324000000", dt.to_rfc3339_with_nano_buf(9));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn write_appends() {
+    let dt = crate::Utc::from_ymd(2024, 1, 5);
+    let mut s = String::from("at ");
+    dt.write_ymd(&mut s).unwrap();
+    s.push(' ');
+    dt.write_rfc7231(&mut s).unwrap();
+    assert_eq!("at 2024-01-05 Fri, 05 Jan 2024 00:00:00 GMT", s);
+
+    let mut bytes = b"> ".to_vec();
+    dt.write_iso8601_with_precision_io(&mut bytes, 0).unwrap();
+    assert_eq!(b"> 2024-01-05 00:00:00", bytes.as_slice());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_precision() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.123456789_f64);
+    assert_eq!("2024-01-09T16:21:00.123456", format!("{dt}"));
+    assert_eq!("2024-01-09T16:21:00.123", format!("{dt:.3}"));
+    assert_eq!("2024-01-09T16:21:00.123456789", format!("{dt:.9}"));
+    assert_eq!("2024-01-09T16:21:00.123456789000", format!("{dt:.12}"));
+    assert_eq!(dt.as_rfc3339_with_nano(12), format!("{dt:.12}"));
+    assert_eq!("2024-01-09T16:21:00.123456789000 |", format!("{dt:<33.12}|"));
+    assert_eq!("2024-01-09T16:21:59.999", format!("{:.3}", crate::Utc::from_ymdhms(
+        2024, 1, 9, 16, 21, 59.9996_f64,
+    )));
+    assert_eq!("2024-01-09T16:21:00", format!("{dt:.0}"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_alternate() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.123456789_f64);
+    assert_eq!(dt.as_iso8601(), format!("{dt:#}"));
+    assert_eq!("2024-01-09 16:21:00.1234", format!("{dt:#.4}"));
+    assert_eq!("2024-01-09 16:21:00", format!("{dt:#.0}"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn display_width_and_alignment() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    assert_eq!("2024-01-09T16:21:00  |", format!("{dt:21.0}|"));
+    assert_eq!("  2024-01-09T16:21:00", format!("{dt:>21.0}"));
+    assert_eq!("*2024-01-09T16:21:00**", format!("{dt:*^22.0}"));
+    assert_eq!("2024-01-09T16:21:00.000000", format!("{dt:>10}"));
+    assert_eq!("    2024-01-09 16:21:00.000", format!("{dt:>#27}"));
+}
+
+#[test]
+fn from_epoch_seconds() {
+    assert_eq!(Some(crate::Utc::epoch()), crate::Utc::from_epoch_seconds(0));
+    assert_eq!(
+        Some(crate::Utc::from_ymdhms(2024, 2, 29, 23, 59, 59_f64)),
+        crate::Utc::from_epoch_seconds(1_709_251_199)
+    );
+    assert_eq!(
+        Some(crate::Utc::from_ymdhms(9999, 12, 31, 23, 59, 59_f64)),
+        crate::Utc::from_epoch_seconds(253_402_300_799)
+    );
+    assert_eq!(None, crate::Utc::from_epoch_seconds(253_402_300_800));
+}
+
+#[test]
+fn from_epoch_millis_and_nanos() {
+    const RTC: Option<crate::Utc> = crate::Utc::from_epoch_millis(1_704_817_260_123);
+    assert_eq!("2024-01-09T16:21:00.123", RTC.unwrap().to_rfc3339_with_nano_buf(3));
+    let dt = crate::Utc::from_epoch_nanos(1_704_817_260_123_456_789).unwrap();
+    assert_eq!(123_456_789, dt.nanosecond());
+    assert_eq!(None, crate::Utc::from_epoch_nanos(253_402_300_800_000_000_000));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn system_time_round_trip() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.25_f64);
+    let time = std::time::SystemTime::from(dt);
+    assert_eq!(Ok(dt), crate::Utc::try_from(time));
+    assert_eq!(
+        Ok(crate::Utc::MAX),
+        crate::Utc::try_from(std::time::SystemTime::from(crate::Utc::MAX))
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn mock_clock_shared_between_threads() {
+    let clock = std::sync::Arc::new(crate::MockClock::new(crate::Utc::from_ymd(2024, 1, 9)));
+    let handles: Vec<_> = (0..4).map(|_| {
+        let clock = clock.clone();
+        std::thread::spawn(move || {
+            for _ in 0..250 {
+                clock.advance(std::time::Duration::from_millis(1));
+            }
+        })
+    }).collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(
+        crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64),
+        crate::Clock::now(&clock)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn offset_clock_follows_inner_clock() {
+    let mock = crate::MockClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let skewed = crate::OffsetClock::behind(&mock, std::time::Duration::from_secs(30));
+    assert_eq!(
+        crate::Utc::from_ymdhms(2024, 1, 8, 23, 59, 30_f64),
+        crate::Clock::now(&skewed)
+    );
+
+    mock.advance(std::time::Duration::from_secs(60));
+    assert_eq!(
+        crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 30_f64),
+        crate::Clock::now(&skewed)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn clock_trait_objects() {
+    let clocks: Vec<Box<dyn crate::Clock>> = vec![
+        Box::new(crate::SystemClock),
+        Box::new(crate::FixedClock::new(crate::Utc::MAX)),
+    ];
+    assert!(clocks[0].now() < clocks[1].now());
+}
+
+#[test]
+fn eq_compares_seconds() {
+    assert_ne!(
+        crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64),
+        crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 1_f64)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn monotonic_across_threads() {
+    let wall = crate::FixedClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let clock = std::sync::Arc::new(crate::MonotonicUtc::with_clock(wall));
+    let handles: Vec<_> = (0..4).map(|_| {
+        let clock = clock.clone();
+        std::thread::spawn(move || {
+            let mut readings = Vec::new();
+            for _ in 0..250 {
+                readings.push(crate::Clock::now(&clock));
+            }
+            readings
+        })
+    }).collect();
+
+    let mut readings = Vec::new();
+    for handle in handles {
+        let thread_readings = handle.join().unwrap();
+        assert!(thread_readings.windows(2).all(|w| w[0] < w[1]));
+        readings.extend(thread_readings);
+    }
+
+    readings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    readings.dedup();
+    assert_eq!(1_000, readings.len());
+    assert_eq!(std::time::Duration::from_nanos(999), clock.drift());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn monotonic_follows_wall_clock_forward() {
+    let wall = crate::MockClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let clock = crate::MonotonicUtc::with_clock(&wall);
+    crate::Clock::now(&clock);
+    crate::Clock::now(&clock);
+    assert_eq!(std::time::Duration::from_nanos(1), clock.drift());
+
+    wall.advance(std::time::Duration::from_secs(1));
+    assert_eq!(std::time::Duration::ZERO, clock.drift());
+    assert_eq!(
+        crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64),
+        crate::Clock::now(&clock)
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn monotonic_before_1970() {
+    let fixed = crate::FixedClock::new(crate::Utc::from_ymd(1960, 1, 1));
+    let fixed = crate::MonotonicUtc::with_clock(fixed);
+    assert_eq!(std::time::Duration::ZERO, fixed.drift());
+    assert_eq!(crate::Utc::from_ymd(1960, 1, 1), crate::Clock::now(&fixed));
+    assert_eq!(std::time::Duration::ZERO, fixed.drift());
+
+    let wall = crate::MockClock::new(crate::Utc::from_ymd(1960, 1, 1));
+    let clock = crate::MonotonicUtc::with_clock(&wall);
+    let a = crate::Clock::now(&clock);
+    assert_eq!(crate::Utc::from_ymd(1960, 1, 1), a);
+    wall.set(crate::Utc::from_ymd(1959, 12, 31));
+    assert_eq!(a + std::time::Duration::from_nanos(1), crate::Clock::now(&clock));
+    assert_eq!(std::time::Duration::new(86_400, 1), clock.drift());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hlc_send_follows_physical_clock() {
+    let wall = crate::MockClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let clock = crate::HybridLogicalClock::with_clock(&wall);
+    let a = clock.send().unwrap();
+    let b = clock.send().unwrap();
+    assert_eq!(crate::HlcTimestamp::new(crate::Utc::from_ymd(2024, 1, 9), 0), a);
+    assert_eq!(crate::HlcTimestamp::new(crate::Utc::from_ymd(2024, 1, 9), 1), b);
+
+    // The physical clock stepping back does not move timestamps back.
+    wall.set(crate::Utc::from_ymd(2024, 1, 8));
+    let c = clock.send().unwrap();
+    assert_eq!(crate::HlcTimestamp::new(crate::Utc::from_ymd(2024, 1, 9), 2), c);
+
+    wall.set(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 0.001_f64));
+    let d = clock.send().unwrap();
+    assert_eq!(0, d.logical());
+    assert!(c < d);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hlc_receive_takes_the_latest() {
+    let wall = crate::FixedClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let clock = crate::HybridLogicalClock::with_clock(wall);
+    let remote = crate::HlcTimestamp::new(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64), 41);
+    assert_eq!(
+        crate::HlcTimestamp::new(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64), 42),
+        clock.receive(remote).unwrap()
+    );
+
+    let stale = crate::HlcTimestamp::new(crate::Utc::from_ymd(2024, 1, 1), 100);
+    assert_eq!(
+        crate::HlcTimestamp::new(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64), 43),
+        clock.receive(stale).unwrap()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hlc_skew_leaves_clock_unchanged() {
+    let wall = crate::FixedClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let clock = crate::HybridLogicalClock::with_clock(wall)
+        .max_skew(std::time::Duration::from_secs(1));
+    let near = crate::HlcTimestamp::new(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1_f64), 0);
+    let far = crate::HlcTimestamp::new(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 1.001_f64), 0);
+    assert!(clock.receive(far).is_err());
+    assert_eq!(crate::HlcTimestamp::MIN, clock.last());
+    assert_eq!(1, clock.receive(near).unwrap().logical());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hlc_logical_overflow() {
+    let wall = crate::FixedClock::new(crate::Utc::from_ymd(2024, 1, 9));
+    let clock = crate::HybridLogicalClock::with_clock(wall);
+    let remote = crate::HlcTimestamp::new(crate::Utc::from_ymd(2024, 1, 9), u16::MAX);
+    assert_eq!(Err(crate::HlcError::LogicalOverflow), clock.receive(remote));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hlc_encoding_orders_like_timestamps() {
+    let timestamps = [
+        crate::HlcTimestamp::MIN,
+        crate::HlcTimestamp::new(crate::Utc::from_ymd(2024, 1, 9), 65_535),
+        crate::HlcTimestamp::new(crate::Utc::from_ymdhms(2024, 1, 9, 0, 0, 0.001_f64), 0),
+        crate::HlcTimestamp::new(crate::Utc::MAX, 65_535),
+    ];
+    for pair in timestamps.windows(2) {
+        assert!(pair[0] < pair[1]);
+        assert!(pair[0].to_u64() < pair[1].to_u64());
+        assert!(pair[0].to_string() < pair[1].to_string());
+    }
+    for ts in timestamps {
+        assert_eq!(Some(ts), crate::HlcTimestamp::from_u64(ts.to_u64()));
+        assert_eq!(Ok(ts), ts.to_string().parse());
+    }
+    assert_eq!(None, crate::HlcTimestamp::from_u64(u64::MAX));
+}
+
+#[test]
+fn hlc_parse_errors() {
+    for text in [
+        "",
+        "2024-01-09T16:21:00.500Z",
+        "2024-01-09T16:21:00.500+00001",
+        "2024-02-30T16:21:00.500Z-00001",
+        "2024-01-09T16:21:00.500Z-65536",
+        "2024-01-09T16:21:00.5a0Z-00001",
+    ] {
+        assert_eq!(Err(crate::ParseHlcError), text.parse::<crate::HlcTimestamp>());
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cached_refresh_reuses_date() {
+    let granularity = 1_000_000;
+    let nanos = crate::Utc::from_ymdhms(2024, 2, 29, 23, 59, 59.998_f64).nanos_since_epoch();
+    let cached = crate::cached::Cached::new(nanos, granularity);
+    assert_eq!("2024-02-29T23:59:59.998", cached.utc().to_rfc3339_with_nano_buf(3));
+
+    let same = cached.refresh(nanos + 500_000, granularity);
+    assert_eq!(cached.utc(), same.utc());
+
+    let later = cached.refresh(nanos + 1_250_000, granularity);
+    assert_eq!("2024-02-29T23:59:59.999", later.utc().to_rfc3339_with_nano_buf(3));
+    assert_eq!(crate::Weekday::Thursday, later.utc().weekday());
+
+    let next_day = later.refresh(nanos + 2_000_000, granularity);
+    assert_eq!(crate::Utc::from_ymd(2024, 3, 1), next_day.utc());
+    assert_eq!("Friday", next_day.utc().day_of_week());
+
+    let next_year = crate::Utc::from_ymd(2025, 1, 1).nanos_since_epoch();
+    assert_eq!(crate::Utc::from_ymd(2025, 1, 1), next_day.refresh(next_year, granularity).utc());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cached_clock_truncates_to_granularity() {
+    let clock = crate::CachedClock::new().granularity(std::time::Duration::from_secs(86_400));
+    let now = crate::Clock::now(&clock);
+    let today = crate::Utc::now();
+    assert_eq!(crate::Utc::from_ymd(today.year(), today.month(), today.day()), now);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn cached_clock_background_keeps_up() {
+    let clock = crate::CachedClock::new()
+        .granularity(std::time::Duration::from_millis(5))
+        .background();
+    let first = crate::Clock::now(&clock);
+    std::thread::sleep(std::time::Duration::from_millis(50));
+    let second = crate::Clock::now(&clock);
+    assert!(first < second);
+    assert!(second <= crate::Utc::now());
+}
+
+#[test]
+fn embedded_leap_seconds_are_at_month_ends() {
+    let leap_days = [
+        (1972, 6, 30), (1972, 12, 31), (1973, 12, 31), (1974, 12, 31), (1975, 12, 31),
+        (1976, 12, 31), (1977, 12, 31), (1978, 12, 31), (1979, 12, 31), (1981, 6, 30),
+        (1982, 6, 30), (1983, 6, 30), (1985, 6, 30), (1987, 12, 31), (1989, 12, 31),
+        (1990, 12, 31), (1992, 6, 30), (1993, 6, 30), (1994, 6, 30), (1995, 12, 31),
+        (1997, 6, 30), (1998, 12, 31), (2005, 12, 31), (2008, 12, 31), (2012, 6, 30),
+        (2015, 6, 30), (2016, 12, 31),
+    ];
+    let table = crate::LeapSeconds::embedded();
+    let mut count = 0;
+    for year in 1970..2030 {
+        for month in 1..=12 {
+            for day in 1..=crate::calendar::days_in_month(year, month) {
+                if table.has_leap_second(year, month, day) {
+                    assert_eq!(leap_days[count], (year, month, day));
+                    count += 1;
+                }
+            }
+        }
+    }
+    assert_eq!(leap_days.len(), count);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn leap_second_ordering() {
+    let before = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 59.999_f64);
+    let leap = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64);
+    let late_leap = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60.999_f64);
+    let after = crate::Utc::from_ymd(2017, 1, 1);
+    assert!(before < leap && leap < late_leap && late_leap < after);
+    assert_ne!(leap, after);
+    assert_eq!("Sat, 31 Dec 2016 23:59:60 GMT", leap.as_rfc7231());
+    assert_eq!("2016-12-31 23:59:60.999", late_leap.as_iso8601());
+}
+
+#[test]
+fn leap_second_strict() {
+    assert!(crate::Utc::from_ymdhms_strict(2015, 6, 30, 23, 59, 60.5_f64).is_ok());
+    for (y, m, d, h, mi, s) in [
+        (2015, 6, 30, 23, 58, 60_f64),
+        (2015, 6, 30, 22, 59, 60_f64),
+        (2015, 12, 31, 23, 59, 60_f64),
+        (2016, 12, 31, 23, 59, 61_f64),
+    ] {
+        assert_eq!(
+            crate::Component::Second,
+            crate::Utc::from_ymdhms_strict(y, m, d, h, mi, s).unwrap_err().component()
+        );
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn parse_leap_second() {
+    let hints = crate::ParseHints::new();
+    let leap = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60.5_f64);
+    assert_eq!(Ok(leap), crate::Utc::parse_any("2016-12-31T23:59:60.5Z", &hints));
+    assert_eq!(Ok(leap), crate::Utc::parse_any("2017-01-01T00:59:60.5+01:00", &hints));
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Rfc3339)),
+        crate::Utc::parse_any("2017-12-31T23:59:60Z", &hints)
+    );
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Rfc3339)),
+        crate::Utc::parse_any("2016-12-31T23:59:60+01:00", &hints)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn leap_second_with_loaded_table() {
+    // A table with a leap second announced after the embedded one, and one
+    // which stops before 2016.
+    let later = crate::LeapSeconds::parse("3692217600 37\n4307472000 38").unwrap();
+    let earlier = crate::LeapSeconds::parse("3644697600 36").unwrap();
+
+    let leap = crate::Utc::from_ymdhms_with(2036, 6, 30, 23, 59, 60.5_f64, &later);
+    assert_eq!("2036-06-30T23:59:60.500000", leap.as_rfc3339_nano());
+    assert_eq!(Ok(leap), crate::Utc::from_ymdhms_strict_with(2036, 6, 30, 23, 59, 60.5_f64, &later));
+    assert_eq!(
+        "2036-07-01T00:00:00",
+        crate::Utc::from_ymdhms_signed_with(2036, 6, 30, 23, 59, 60_f64, &earlier).as_rfc3339()
+    );
+    assert_eq!(
+        crate::Component::Second,
+        crate::Utc::from_ymdhms_strict_with(2016, 12, 31, 23, 59, 60_f64, &earlier)
+            .unwrap_err().component()
+    );
+
+    let hints = crate::ParseHints::new().leap_seconds(later.clone());
+    assert_eq!(Ok(leap), crate::Utc::parse_any("2036-07-01T00:59:60.5+01:00", &hints));
+    let hints = crate::ParseHints::new().leap_seconds(earlier);
+    assert_eq!(
+        Err(crate::ParseAnyError::Invalid(crate::Format::Rfc3339)),
+        crate::Utc::parse_any("2016-12-31T23:59:60Z", &hints)
+    );
+
+    let (a, b) = (crate::Utc::from_ymd(2030, 1, 1), crate::Utc::from_ymd(2040, 1, 1));
+    assert_eq!(0, crate::Utc::leap_seconds_between(&a, &b));
+    assert_eq!(1, crate::Utc::leap_seconds_between_with(&a, &b, &later));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn leap_seconds_list() {
+    let list = "\
+#	Updated through IERS Bulletin C 70
+#$	 3929093563
+#@	3976214400
+#
+2272060800	10	# 1 Jan 1972
+2287785600	11	# 1 Jul 1972
+2303683200	12	# 1 Jan 1973
+#h	16edd0f0 3666784f 37db6bdd e74ced87 59af48f1
+";
+    let table: crate::LeapSeconds = list.parse().unwrap();
+    assert!(table.has_leap_second(1972, 12, 31));
+    assert!(!table.has_leap_second(2016, 12, 31));
+    assert_eq!(Some(crate::Utc::from_ymd(2026, 1, 1)), table.expires());
+    assert_eq!(2, table.leap_seconds_between(&crate::Utc::epoch(), &crate::Utc::from_ymd(2024, 1, 1)));
+
+    assert!(matches!(
+        crate::LeapSeconds::parse("2287785600\t11\n2272060800\t10\n"),
+        Err(crate::LeapSecondsError::NotIncreasing(2))
+    ));
+    assert!(matches!(
+        crate::LeapSeconds::parse("# nothing\n"),
+        Err(crate::LeapSecondsError::Empty)
+    ));
+    assert!(matches!(
+        crate::LeapSeconds::parse("2272060800\tten\n"),
+        Err(crate::LeapSecondsError::InvalidLine(1))
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tai_round_trips_across_leap_seconds() {
+    let mut dt = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 58.5_f64);
+    let mut previous = crate::Tai::from_utc(&dt);
+    for expected in ["23:59:59.500", "23:59:60.500", "00:00:00.500", "00:00:01.500"] {
+        let tai = crate::Tai::from_duration_since_epoch(
+            previous.duration_since_epoch() + std::time::Duration::from_secs(1)
+        );
+        dt = tai.to_utc().unwrap();
+        assert_eq!(expected, &dt.to_iso8601_with_precision_buf(3)[11..]);
+        assert_eq!(tai, crate::Tai::from_utc(&dt));
+        previous = tai;
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tai_offsets() {
+    let epoch = crate::Tai::from_utc(&crate::Utc::epoch());
+    assert_eq!(std::time::Duration::from_secs(10), epoch.duration_since_epoch());
+    assert_eq!(Some(crate::Utc::epoch()), epoch.to_utc());
+    let tai = crate::Tai::from_duration_since_epoch(std::time::Duration::from_secs(9));
+    assert_eq!(Some(crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 59_f64)), tai.to_utc());
+    assert_eq!(tai, crate::Tai::from_utc(&tai.to_utc().unwrap()));
+
+    let tai = crate::Tai::from_utc(&crate::Utc::from_ymd(1999, 1, 1));
+    assert_eq!("1999-01-01T00:00:32.000000 TAI", tai.to_string());
+    assert_eq!("1999-01-01 00:00:32.000 TAI", format!("{tai:#}"));
+    assert_eq!(Ok(crate::Utc::MAX), crate::Utc::try_from(crate::Tai::from(crate::Utc::MAX)));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tai_with_loaded_table() {
+    let table = crate::LeapSeconds::parse("2272060800 10\n3692217600 11\n").unwrap();
+    let dt = crate::Utc::from_ymd(2096, 1, 1);
+    assert_eq!(
+        crate::Tai::from_utc(&dt).duration_since_epoch() - std::time::Duration::from_secs(26),
+        crate::Tai::from_utc_with(&dt, &table).duration_since_epoch()
+    );
+
+    // The table only steps at the start of 2017, so earlier leap seconds
+    // are missing from it.
+    let leap = crate::Tai::from_duration_since_epoch(std::time::Duration::from_secs(
+        3_692_217_600 - 2_208_988_800 + 10
+    ));
+    assert_eq!(
+        "2016-12-31T23:59:60",
+        leap.to_utc_with(&table).unwrap().to_rfc3339_buf()
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn time_scale_display_beyond_civil_range() {
+    let gps = crate::Gps::from_week(u32::MAX, core::time::Duration::from_millis(1_500)).unwrap();
+    assert_eq!("2597596220016001.500000 s GPS", gps.to_string());
+    assert_eq!("2597596220016001.5 s GPS", format!("{gps:.1}"));
+    assert_eq!("2597596220016001 s GPS   ", format!("{gps:<25.0}"));
+
+    let tai = crate::Tai::from_duration_since_epoch(core::time::Duration::from_secs(u64::MAX));
+    assert_eq!(">18446744073709551615.000 s TAI", format!("{tai:>>31.3}"));
+
+    let tai = crate::Tai::from_nanos_since_epoch(-62_135_596_801_463_000_000);
+    assert_eq!("-62135596801.463 s TAI", format!("{tai:#}"));
+
+    // Width and alignment within range too.
+    let tai = crate::Tai::from_utc(&crate::Utc::from_ymd(2024, 1, 9));
+    assert_eq!("  2024-01-09T00:00:37.0 TAI", format!("{tai:>27.1}"));
+    assert_eq!("*2024-01-09T00:00:37.0 TAI*", format!("{tai:*^27.1}"));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn gps_epoch_and_weeks() {
+    let epoch = crate::Gps::from_utc(&crate::Utc::from_ymd(1980, 1, 6)).unwrap();
+    assert_eq!(crate::Gps::EPOCH, epoch);
+    assert_eq!("Sunday", crate::Utc::from_ymd(1980, 1, 6).day_of_week());
+    assert_eq!(None, crate::Gps::from_utc(&crate::Utc::from_ymd(1980, 1, 5)));
+    assert_eq!(
+        crate::Tai::from(epoch).duration_since_epoch(),
+        crate::Tai::from_utc(&crate::Utc::from_ymd(1980, 1, 6)).duration_since_epoch()
+    );
+
+    // The first rollover of the 10 bit week was at midnight GPS time, 13
+    // seconds ahead of UTC by then.
+    let rollover = crate::Gps::from_utc(&crate::Utc::from_ymdhms(1999, 8, 21, 23, 59, 47_f64)).unwrap();
+    assert_eq!(1024, rollover.week());
+    assert_eq!(0, rollover.rolled_over_week(1024));
+    assert_eq!(std::time::Duration::ZERO, rollover.time_of_week());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn gps_rolled_over_week_picks_nearest() {
+    let tow = std::time::Duration::from_secs(3_600);
+    for (reference, expected) in [
+        (crate::Utc::from_ymd(1985, 1, 1), 1000),
+        (crate::Utc::from_ymd(2000, 1, 1), 1000),
+        (crate::Utc::from_ymd(2010, 1, 1), 2024),
+        (crate::Utc::from_ymd(2019, 4, 7), 2024),
+        (crate::Utc::from_ymd(2040, 1, 1), 3048),
+    ] {
+        let gps = crate::Gps::from_rolled_over_week(1000, tow, 1024, &reference).unwrap();
+        assert_eq!(expected, gps.week());
+        assert_eq!(tow, gps.time_of_week());
+    }
+    assert_eq!(None, crate::Gps::from_rolled_over_week(1000, tow, 0, &crate::Utc::epoch()));
+}
+
+#[test]
+fn smear_noon_to_noon() {
+    let smear = crate::LeapSmear::new();
+    for dt in [
+        crate::Utc::from_ymdhms(2016, 12, 31, 11, 59, 59_f64),
+        crate::Utc::from_ymdhms(2016, 12, 31, 12, 0, 0_f64),
+        crate::Utc::from_ymdhms(2017, 1, 1, 12, 0, 0_f64),
+        crate::Utc::from_ymd(2018, 1, 1),
+    ] {
+        assert_eq!(dt, smear.smear(&dt));
+        assert_eq!(dt, smear.unsmear(&dt));
+    }
+
+    // Half a second behind at the leap second, and only caught up at noon.
+    let smeared = smear.smear(&crate::Utc::from_ymdhms(2017, 1, 1, 11, 59, 59_f64));
+    assert_eq!("2017-01-01T11:59:59.000011", smeared.to_rfc3339_with_nano_buf(6));
+    let smeared = smear.smear(&crate::Utc::from_ymd(2017, 1, 1));
+    assert_eq!("2017-01-01T00:00:00.499994", smeared.to_rfc3339_with_nano_buf(6));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn smear_round_trips_and_never_steps() {
+    for smear in [
+        crate::LeapSmear::new(),
+        crate::LeapSmear::new().shape(crate::SmearShape::Cosine),
+        crate::LeapSmear::new().window(std::time::Duration::from_secs(10)),
+    ] {
+        let mut dt = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 50_f64);
+        let mut previous = smear.smear(&dt);
+        for _ in 0..40 {
+            let tai = crate::Tai::from_utc(&dt).duration_since_epoch() +
+                std::time::Duration::from_millis(500);
+            dt = crate::Tai::from_duration_since_epoch(tai).to_utc().unwrap();
+            let smeared = smear.smear(&dt);
+            assert!(!smeared.is_leap_second());
+            assert!(smeared > previous);
+            // A smeared nanosecond can be either of two true ones.
+            let unsmeared = crate::Tai::from_utc(&smear.unsmear(&smeared));
+            let tai = crate::Tai::from_utc(&dt);
+            assert!(unsmeared <= tai);
+            assert!(
+                tai.duration_since_epoch() - unsmeared.duration_since_epoch() <=
+                    std::time::Duration::from_nanos(1)
+            );
+            previous = smeared;
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn smear_with_loaded_table() {
+    let table = crate::LeapSeconds::parse("2272060800 10\n3692217600 11\n").unwrap();
+    let smear = crate::LeapSmear::new().leap_seconds(table);
+    let dt = crate::Utc::from_ymd(2015, 7, 1);
+    assert_eq!(dt, smear.smear(&dt));
+    assert_ne!(dt, crate::LeapSmear::new().smear(&dt));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn smeared_clock() {
+    let wall = crate::MockClock::new(crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64));
+    let clock = crate::SmearedClock::new(&wall, crate::LeapSmear::new());
+    assert_eq!(
+        "2016-12-31T23:59:59.500",
+        crate::Clock::now(&clock).to_rfc3339_with_nano_buf(3)
+    );
+    wall.set(crate::Utc::from_ymd(2024, 1, 9));
+    assert_eq!(crate::Utc::from_ymd(2024, 1, 9), crate::Clock::now(&clock));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tai64_labels() {
+    let epoch = crate::Tai64::from_utc(&crate::Utc::epoch());
+    assert_eq!((1 << 62) + 10, epoch.label());
+    assert_eq!("@400000000000000a", epoch.to_string());
+    assert_eq!(Some(crate::Utc::epoch()), epoch.to_utc());
+
+    // Before 1970 TAI and with the reserved top bit set.
+    let early: crate::Tai64 = "@3fffffffffffffff".parse().unwrap();
+    assert_eq!(Some(crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 49_f64)), early.to_utc());
+    assert_eq!(early, crate::Tai64::from_utc(&crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 49.5_f64)));
+    assert_eq!(None, crate::Tai64::from_bytes([0x80, 0, 0, 0, 0, 0, 0, 0]));
+    assert_eq!(Err(crate::ParseTai64Error), "@8000000000000000".parse::<crate::Tai64>());
+    assert_eq!(Err(crate::ParseTai64Error), "400000000000000a".parse::<crate::Tai64>());
+    assert_eq!(Err(crate::ParseTai64Error), "@400000000000000".parse::<crate::Tai64>());
+    assert_eq!(Err(crate::ParseTai64Error), "@400000000000000g".parse::<crate::Tai64>());
+    assert_eq!(Ok(epoch), "@400000000000000A".parse());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tai64n_labels() {
+    let leap = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60.25_f64);
+    let label = crate::Tai64N::from_utc(&leap);
+    assert_eq!(0x4000_0000_5868_4680 + 36, label.tai64().label());
+    assert_eq!(250_000_000, label.nanos());
+    assert_eq!(Some(leap), label.to_utc());
+    assert_eq!(Some(label), crate::Tai64N::from_bytes(label.to_bytes()));
+    assert_eq!(Ok(label), label.to_string().parse());
+    assert!(label < crate::Tai64N::from_utc(&crate::Utc::from_ymd(2017, 1, 1)));
+
+    let mut bytes = label.to_bytes();
+    bytes[8..].copy_from_slice(&1_000_000_000_u32.to_be_bytes());
+    assert_eq!(None, crate::Tai64N::from_bytes(bytes));
+    assert_eq!(Err(crate::ParseTai64Error), "@4000000058684680".parse::<crate::Tai64N>());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tai64na_labels() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    let label = crate::Tai64NA::from_utc(&dt);
+    assert_eq!(0, label.attos());
+    assert_eq!(crate::Tai64N::from_utc(&dt), label.tai64n());
+    assert_eq!("@40000000659d72911dcd650000000000", label.to_string());
+    assert_eq!(Some(label), crate::Tai64NA::from_bytes(label.to_bytes()));
+
+    let mut bytes = label.to_bytes();
+    bytes[15] = 1;
+    let later = crate::Tai64NA::from_bytes(bytes).unwrap();
+    assert!(label < later);
+    assert_eq!(later.to_utc(), label.to_utc());
+    bytes[12..].copy_from_slice(&1_000_000_000_u32.to_be_bytes());
+    assert_eq!(None, crate::Tai64NA::from_bytes(bytes));
+}
+
+#[test]
+fn ntp_fraction_rounding() {
+    // 2⁻³² s is about 0.23 ns, so every nanosecond round trips.
+    for nano in [0, 1, 2, 3, 499_999_999, 500_000_000, 999_999_998, 999_999_999] {
+        let dt = crate::Utc::from_epoch_nanos(1_704_817_260_000_000_000 + nano).unwrap();
+        let ntp = crate::NtpTimestamp::from_utc(&dt);
+        assert_eq!(Some(dt), ntp.to_utc());
+    }
+
+    // Fractions are rounded to the nearest nanosecond, carrying into the
+    // next second.
+    let ntp = crate::NtpTimestamp::new(0xe947_f0ec, 3);
+    assert_eq!(1_f64 / 1_000_000_000_f64, ntp.to_utc().unwrap().nano());
+    let ntp = crate::NtpTimestamp::new(0xe947_f0ec, 2);
+    assert_eq!(0_f64, ntp.to_utc().unwrap().nano());
+    let ntp = crate::NtpTimestamp::new(0xe947_f0ec, u32::MAX);
+    assert_eq!(Some(crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 1_f64)), ntp.to_utc());
+}
+
+#[test]
+fn ntp_eras() {
+    let rollover = crate::Utc::from_ymdhms(2036, 2, 7, 6, 28, 16_f64);
+    let before = crate::Utc::from_ymdhms(2036, 2, 7, 6, 28, 15.5_f64);
+    assert_eq!(crate::NtpTimestamp::new(0, 0), crate::NtpTimestamp::from_utc(&rollover));
+    assert_eq!(
+        crate::NtpTimestamp::new(u32::MAX, 1 << 31),
+        crate::NtpTimestamp::from_utc(&before)
+    );
+    assert_eq!(Some(rollover), crate::NtpTimestamp::new(0, 0).to_utc());
+    assert_eq!(Some(before), crate::NtpTimestamp::from_utc(&before).to_utc());
+
+    // Either side of the rollover from the other.
+    assert_eq!(Some(before), crate::NtpTimestamp::from_utc(&before).to_utc_near(&rollover));
+    assert_eq!(Some(rollover), crate::NtpTimestamp::from_utc(&rollover).to_utc_near(&before));
+
+    // RFC 4330 takes 1968 onwards.
+    let ntp = crate::NtpTimestamp::from_utc(&crate::Utc::epoch());
+    assert_eq!(2_208_988_800, ntp.seconds());
+    assert_eq!(Some(crate::Utc::epoch()), ntp.to_utc());
+    assert_eq!(
+        Some(crate::Utc::from_ymdhms(1968, 1, 20, 3, 14, 8_f64)),
+        crate::NtpTimestamp::new(0x8000_0000, 0).to_utc()
+    );
+    assert_eq!(Some(crate::Utc::from_ymdhms(1833, 11, 24, 17, 31, 44_f64)), ntp.to_utc_in_era(-1));
+    assert_eq!(None, ntp.to_utc_in_era(-15));
+
+    // Fractions before 1970 and 1900 round trip.
+    let dt = crate::Utc::from_ymdhms(1800, 1, 1, 0, 0, 0.5_f64);
+    let ntp = crate::NtpTimestamp::from_utc(&dt);
+    assert_eq!((1 << 31, -1), (ntp.fraction(), crate::NtpTimestamp::era_of(&dt)));
+    assert_eq!(Some(dt), ntp.to_utc_near(&dt));
+    assert_eq!(Some(dt), ntp.to_utc_in_era(-1));
+    let dt = crate::Utc::from_ymdhms(1899, 12, 31, 23, 59, 59.25_f64);
+    let ntp = crate::NtpTimestamp::from_utc(&dt);
+    assert_eq!((u32::MAX, 1 << 30), (ntp.seconds(), ntp.fraction()));
+    assert_eq!(-1, crate::NtpTimestamp::era_of(&dt));
+    assert_eq!(Some(dt), ntp.to_utc_in_era(-1));
+    let dt = crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 59.75_f64);
+    assert_eq!(Some(dt), crate::NtpTimestamp::from_utc(&dt).to_utc());
+
+    let far = crate::Utc::from_ymd(9999, 12, 31);
+    assert_eq!(59, crate::NtpTimestamp::era_of(&far));
+    assert_eq!(Some(far), crate::NtpTimestamp::from_utc(&far).to_utc_in_era(59));
+    assert_eq!(Some(far), crate::NtpTimestamp::from_utc(&far).to_utc_near(&far));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn ntp_short_format() {
+    assert_eq!(std::time::Duration::ZERO, crate::NtpShort::new(0, 0).to_duration());
+    assert_eq!(
+        std::time::Duration::new(1, 15_259),
+        crate::NtpShort::new(1, 1).to_duration()
+    );
+    assert_eq!(
+        std::time::Duration::from_secs(65_536),
+        crate::NtpShort::from_u32(u32::MAX).to_duration() + std::time::Duration::from_nanos(15_259)
+    );
+    assert_eq!(
+        Some(crate::NtpShort::new(1, 0)),
+        crate::NtpShort::from_duration(std::time::Duration::new(0, 999_999_999))
+    );
+    assert_eq!(
+        None,
+        crate::NtpShort::from_duration(std::time::Duration::new(65_535, 999_999_999))
+    );
+    let short = crate::NtpShort::from_bytes([0x00, 0x02, 0x40, 0x00]);
+    assert_eq!(crate::NtpShort::new(2, 0x4000), short);
+    assert_eq!([0x00, 0x02, 0x40, 0x00], short.to_bytes());
+    assert_eq!(std::time::Duration::from_millis(2_250), short.to_duration());
+}
+
+/// Starts a stand-in SNTP server on localhost which answers one request
+/// with the reply made from it, if any, and gets its address.
+#[cfg(feature = "std")]
+fn sntp_stand_in(
+    reply: impl FnOnce([u8; 48]) -> Option<[u8; 48]> + Send + 'static,
+) -> String {
+    let socket = std::net::UdpSocket::bind("127.0.0.1:0").unwrap();
+    let address = socket.local_addr().unwrap().to_string();
+    std::thread::spawn(move || {
+        let mut request = [0; 48];
+        let (_, client) = socket.recv_from(&mut request).unwrap();
+        if let Some(reply) = reply(request) {
+            socket.send_to(&reply, client).unwrap();
+        }
+    });
+    address
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sntp_measures_offset_and_delay() {
+    let local = crate::Utc::from_ymd(2024, 1, 9);
+    let clock = std::sync::Arc::new(crate::MockClock::new(local));
+    let server_clock = clock.clone();
+    let server = sntp_stand_in(move |request| {
+        assert_eq!(0x23, request[0]);
+        // The server is 5 s ahead, takes 20 ms to reply and the round trip
+        // takes 100 ms.
+        let ahead = |millis: u64| crate::NtpTimestamp::from_utc(
+            &(local + std::time::Duration::from_millis(5_000 + millis))
+        ).to_bytes();
+        let mut reply = [0; 48];
+        reply[0] = 0x24;
+        reply[1] = 2;
+        reply[4..8].copy_from_slice(&crate::NtpShort::new(0, 0x8000).to_bytes());
+        reply[24..32].copy_from_slice(&request[40..]);
+        reply[32..40].copy_from_slice(&ahead(40));
+        reply[40..].copy_from_slice(&ahead(60));
+        server_clock.advance(std::time::Duration::from_millis(100));
+        Some(reply)
+    });
+
+    let response = crate::SntpClient::with_clock(server, clock).query().unwrap();
+    assert_eq!(local + std::time::Duration::from_millis(5_060), response.server_time());
+    assert_eq!(std::time::Duration::from_millis(80), response.delay());
+    assert_eq!(5_000_000_000, response.offset_nanos());
+    assert_eq!(2, response.stratum());
+    assert_eq!(std::time::Duration::from_millis(500), response.root_delay());
+    let corrected = response.corrected(crate::FixedClock::new(local));
+    assert_eq!(local + std::time::Duration::from_secs(5), crate::Clock::now(&corrected));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sntp_kiss_of_death() {
+    let server = sntp_stand_in(|request| {
+        let mut reply = [0; 48];
+        reply[0] = 0xe4;
+        reply[12..16].copy_from_slice(b"RATE");
+        reply[24..32].copy_from_slice(&request[40..]);
+        Some(reply)
+    });
+
+    let error = crate::SntpClient::new(server).query().unwrap_err();
+    assert!(matches!(error, crate::SntpError::KissOfDeath(code) if &code == b"RATE"));
+    assert_eq!("server sent kiss-of-death RATE", error.to_string());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sntp_rejects_invalid_replies() {
+    // Not echoing the request's transmit timestamp.
+    let server = sntp_stand_in(|_| {
+        let mut reply = [0; 48];
+        reply[0] = 0x24;
+        reply[1] = 1;
+        Some(reply)
+    });
+    let error = crate::SntpClient::new(server).query().unwrap_err();
+    assert!(matches!(error, crate::SntpError::InvalidResponse));
+
+    // Alarm condition, the server is not synchronised.
+    let server = sntp_stand_in(|request| {
+        let mut reply = [0; 48];
+        reply[0] = 0xe4;
+        reply[1] = 3;
+        reply[24..32].copy_from_slice(&request[40..]);
+        Some(reply)
+    });
+    let error = crate::SntpClient::new(server).query().unwrap_err();
+    assert!(matches!(error, crate::SntpError::Unsynchronized));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn sntp_times_out() {
+    let server = sntp_stand_in(|_| None);
+    let error = crate::SntpClient::new(server)
+        .timeout(std::time::Duration::from_millis(50))
+        .query()
+        .unwrap_err();
+    assert!(matches!(error, crate::SntpError::Timeout));
+}
+
+#[test]
+fn filetime_range() {
+    let start = crate::Utc::from_ymd(1601, 1, 1);
+    assert_eq!(Some(crate::FileTime::new(0)), crate::FileTime::from_utc(&start));
+    assert_eq!(Some(start), crate::FileTime::new(0).to_utc());
+    assert!(crate::FileTime::try_from(crate::Utc::from_ymdhms(1600, 12, 31, 23, 59, 59.9999999_f64)).is_err());
+
+    // Rounded down to the tick, before the unix epoch too.
+    let dt = crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 59.99999995_f64);
+    let filetime = crate::FileTime::from_utc(&dt).unwrap();
+    assert_eq!(116_444_735_999_999_999, filetime.ticks());
+    assert_eq!(
+        "1969-12-31T23:59:59.999999900",
+        filetime.to_utc().unwrap().to_rfc3339_with_nano_buf(9)
+    );
+
+    // Beyond 9999.
+    assert_eq!(None, crate::FileTime::new(u64::MAX).to_utc());
+    assert!(crate::Utc::try_from(crate::FileTime::from_parts(u32::MAX, u32::MAX)).is_err());
+    let filetime = crate::FileTime::new(2_650_467_743_999_999_999);
+    let far = filetime.to_utc().unwrap();
+    assert_eq!("9999-12-31T23:59:59.999999900", far.to_rfc3339_with_nano_buf(9));
+    assert_eq!(Some(filetime), crate::FileTime::from_utc(&far));
+    assert_eq!(filetime, crate::FileTime::from_bytes(filetime.to_bytes()));
+    assert_eq!(None, crate::FileTime::new(filetime.ticks() + 1).to_utc());
+}
+
+#[test]
+fn dotnet_ticks_and_binary() {
+    let min = crate::DotNetDateTime::from_utc(&crate::Utc::MIN);
+    assert_eq!(0, min.ticks());
+    assert_eq!(crate::Utc::MIN, min.to_utc());
+    let max = crate::DotNetDateTime::from_utc(&crate::Utc::MAX);
+    assert_eq!(3_155_378_975_999_999_999, max.ticks());
+    assert_eq!(None, crate::DotNetDateTime::new(max.ticks() + 1, crate::DateTimeKind::Utc));
+
+    let epoch = crate::DotNetDateTime::new(621_355_968_000_000_000, crate::DateTimeKind::Unspecified)
+        .unwrap();
+    assert_eq!(crate::Utc::epoch(), crate::Utc::from(epoch));
+    assert_eq!(621_355_968_000_000_000, epoch.to_binary());
+    assert_eq!(Some(epoch), crate::DotNetDateTime::from_binary(epoch.to_binary()));
+
+    // Local times are stored in UTC, and both local kinds decode as local.
+    let local = crate::DotNetDateTime::new(epoch.ticks(), crate::DateTimeKind::Local).unwrap();
+    assert_eq!(0x889f_7ff5_f7b5_8000_u64 as i64, local.to_binary());
+    assert_eq!(Some(local), crate::DotNetDateTime::from_binary(0xc89f_7ff5_f7b5_8000_u64 as i64));
+
+    // A local time before 0001 in UTC wraps around.
+    let wrapped = (1_u64 << 63 | ((1 << 62) - 18_000_000_000)) as i64;
+    assert_eq!(None, crate::DotNetDateTime::from_binary(wrapped));
+    assert_eq!(None, crate::DotNetDateTime::from_binary(0x7fff_ffff_ffff_ffff));
+}
+
+#[test]
+fn win32_system_time() {
+    let start = crate::Win32SystemTime::from_utc(&crate::Utc::from_ymd(1601, 1, 1)).unwrap();
+    assert_eq!((1601, 1, 1), (start.year(), start.month(), start.day()));
+    assert_eq!(1, start.day_of_week());
+    assert_eq!(None, crate::Win32SystemTime::from_utc(&crate::Utc::from_ymd(1600, 12, 31)));
+
+    let sunday = crate::Utc::from_ymdhms(2024, 1, 7, 23, 59, 59.9996_f64);
+    let system_time = crate::Win32SystemTime::from_utc(&sunday).unwrap();
+    assert_eq!(0, system_time.day_of_week());
+    assert_eq!((23, 59, 59, 999), (
+        system_time.hour(), system_time.minute(), system_time.second(), system_time.milliseconds()
+    ));
+    assert_eq!(
+        [0xe8, 0x07, 1, 0, 0, 0, 7, 0, 23, 0, 59, 0, 59, 0, 0xe7, 0x03],
+        system_time.to_bytes()
+    );
+
+    let leap = crate::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64);
+    let system_time = crate::Win32SystemTime::from_utc(&leap).unwrap();
+    assert_eq!(60, system_time.second());
+    assert_eq!(Ok(leap), system_time.to_utc());
+
+    let mut bytes = system_time.to_bytes();
+    bytes[0..2].copy_from_slice(&2015_u16.to_le_bytes());
+    assert_eq!(
+        crate::Component::Second,
+        crate::Win32SystemTime::from_bytes(bytes).to_utc().unwrap_err().component()
+    );
+    bytes[0..2].copy_from_slice(&1600_u16.to_le_bytes());
+    assert_eq!(
+        crate::Component::Year,
+        crate::Win32SystemTime::from_bytes(bytes).to_utc().unwrap_err().component()
+    );
+    let mut bytes = start.to_bytes();
+    bytes[14..16].copy_from_slice(&1_000_u16.to_le_bytes());
+    assert!(crate::Win32SystemTime::from_bytes(bytes).to_utc().is_err());
+}
+
+#[test]
+fn excel_1900_serials() {
+    let system = crate::ExcelDateSystem::default();
+    assert_eq!(Some(0_f64), system.to_serial(&crate::Utc::from_ymd(1899, 12, 31)));
+    assert_eq!(None, system.to_serial(&crate::Utc::from_ymd(1899, 12, 30)));
+    assert_eq!(Some(1_f64), system.to_serial(&crate::Utc::from_ymd(1900, 1, 1)));
+    let last = crate::Utc::from_ymdhms(1900, 2, 28, 23, 59, 59.999_f64);
+    assert_eq!(Some(59_f64 + 86_399_999_f64 / 86_400_000_f64), system.to_serial(&last));
+    assert_eq!(Some(61_f64), system.to_serial(&crate::Utc::from_ymd(1900, 3, 1)));
+    assert_eq!(Some(2_958_465_f64), system.to_serial(&crate::Utc::from_ymd(9999, 12, 31)));
+
+    assert_eq!(Ok(crate::Utc::from_ymd(1899, 12, 31)), system.from_serial(0_f64));
+    assert_eq!(crate::Component::Day, system.from_serial(60_f64).unwrap_err().component());
+    assert_eq!(crate::Component::Day, system.from_serial(60.999_f64).unwrap_err().component());
+    assert_eq!(Ok(crate::Utc::from_ymd(1900, 3, 1)), system.from_serial(61_f64));
+    assert_eq!(crate::Component::Year, system.from_serial(-0.5_f64).unwrap_err().component());
+    assert_eq!(crate::Component::Year, system.from_serial(2_958_466_f64).unwrap_err().component());
+    assert_eq!(crate::Component::Year, system.from_serial(f64::NAN).unwrap_err().component());
+
+    // Rounded to the millisecond.
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0.0004_f64);
+    assert_eq!(Some(45_300.5_f64), system.to_serial(&dt));
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0.0005_f64);
+    let rounded = system.from_serial(system.to_serial(&dt).unwrap()).unwrap();
+    assert_eq!("2024-01-09T12:00:00.001000000", rounded.to_rfc3339_with_nano_buf(9));
+}
+
+#[test]
+fn excel_1904_serials() {
+    let system = crate::ExcelDateSystem::Date1904;
+    assert_eq!(Some(0_f64), system.to_serial(&crate::Utc::from_ymd(1904, 1, 1)));
+    assert_eq!(Some(2_957_003_f64), system.to_serial(&crate::Utc::from_ymd(9999, 12, 31)));
+    assert_eq!(Ok(crate::Utc::from_ymd(1904, 2, 29)), system.from_serial(59_f64));
+    assert_eq!(crate::Component::Year, system.from_serial(-1_f64).unwrap_err().component());
+
+    // The two systems are 1,462 days apart after 1900-02-28.
+    let dt = crate::Utc::from_ymdhms(1999, 12, 31, 23, 59, 59.999_f64);
+    let serial = system.to_serial(&dt).unwrap();
+    assert_eq!(Some(serial + 1_462_f64), crate::ExcelDateSystem::Date1900.to_serial(&dt));
+    assert_eq!(
+        "1999-12-31T23:59:59.999000000",
+        system.from_serial(serial).unwrap().to_rfc3339_with_nano_buf(9)
+    );
+}
+
+#[test]
+fn ole_automation_dates() {
+    let date = |value: f64| crate::OleDate::new(value).to_utc();
+    assert_eq!(Some(crate::Utc::from_ymd(1899, 12, 30)), date(0_f64));
+    assert_eq!(Some(crate::Utc::from_ymd(1899, 12, 29)), date(-1_f64));
+    assert_eq!(Some(crate::Utc::from_ymdhms(1899, 12, 30, 6, 0, 0_f64)), date(-0.25_f64));
+    assert_eq!(Some(crate::Utc::from_ymdhms(1899, 12, 28, 18, 0, 0_f64)), date(-2.75_f64));
+    assert_eq!(Some(crate::Utc::from_ymd(100, 1, 1)), date(-657_434_f64));
+    assert_eq!(None, date(-657_435_f64));
+    assert_eq!(None, date(2_958_466_f64));
+    assert_eq!(None, date(f64::INFINITY));
+
+    let ole = |dt: crate::Utc| crate::OleDate::from_utc(&dt).map(f64::from);
+    assert_eq!(Some(-2.75_f64), ole(crate::Utc::from_ymdhms(1899, 12, 28, 18, 0, 0_f64)));
+    assert_eq!(Some(-1_f64), ole(crate::Utc::from_ymd(1899, 12, 29)));
+    assert_eq!(Some(0.25_f64), ole(crate::Utc::from_ymdhms(1899, 12, 30, 6, 0, 0_f64)));
+    assert_eq!(Some(2_958_465.999_988_426), ole(crate::Utc::from_ymdhms(9999, 12, 31, 23, 59, 59_f64)));
+
+    // Every millisecond round trips, before day 0 too.
+    for millis in [1_i64, 999, 43_200_000, 86_399_999] {
+        for day in [-657_434_i64, -2, -1, 0, 1, 2_958_465] {
+            let dt = crate::Utc::from_ymd(1899, 12, 30) +
+                core::time::Duration::from_millis(millis as u64);
+            let dt = if day < 0 {
+                dt - core::time::Duration::from_secs(day.unsigned_abs() * 86_400)
+            } else {
+                dt + core::time::Duration::from_secs(day as u64 * 86_400)
+            };
+            assert_eq!(Some(dt), crate::OleDate::from_utc(&dt).unwrap().to_utc());
+        }
+    }
+}
+
+#[test]
+fn dos_date_time_rounding() {
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 1_f64);
+    let round = |dt: &crate::Utc, rounding| crate::DosDateTime::from_utc(dt, rounding)
+        .map(|dos| dos.to_utc().unwrap().to_rfc3339_with_nano_buf(0));
+    assert_eq!(Some("2024-01-09T16:21:00"), round(&dt, crate::DosRounding::Down).as_deref());
+    assert_eq!(Some("2024-01-09T16:21:02"), round(&dt, crate::DosRounding::Nearest).as_deref());
+    assert_eq!(Some("2024-01-09T16:21:02"), round(&dt, crate::DosRounding::Up).as_deref());
+
+    let dt = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.999_f64);
+    assert_eq!(Some("2024-01-09T16:21:00"), round(&dt, crate::DosRounding::Nearest).as_deref());
+    assert_eq!(Some("2024-01-09T16:21:02"), round(&dt, crate::DosRounding::Up).as_deref());
+
+    // Even seconds stay put, and rounding carries into the next year.
+    let dt = crate::Utc::from_ymdhms(1999, 12, 31, 23, 59, 58_f64);
+    assert_eq!(Some("1999-12-31T23:59:58"), round(&dt, crate::DosRounding::Up).as_deref());
+    let dt = crate::Utc::from_ymdhms(1999, 12, 31, 23, 59, 58.5_f64);
+    assert_eq!(Some("2000-01-01T00:00:00"), round(&dt, crate::DosRounding::Up).as_deref());
+
+    // From 1980 to 2107.
+    let start = crate::Utc::from_ymd(1980, 1, 1);
+    let dos = crate::DosDateTime::from_utc(&start, crate::DosRounding::Down).unwrap();
+    assert_eq!((0x0021, 0), (dos.date(), dos.time()));
+    let before = crate::Utc::from_ymdhms(1979, 12, 31, 23, 59, 59_f64);
+    assert_eq!(None, crate::DosDateTime::from_utc(&before, crate::DosRounding::Down));
+    assert_eq!(Some(dos), crate::DosDateTime::from_utc(&before, crate::DosRounding::Up));
+    let end = crate::Utc::from_ymdhms(2107, 12, 31, 23, 59, 58_f64);
+    let dos = crate::DosDateTime::from_utc(&end, crate::DosRounding::Down).unwrap();
+    assert_eq!((0xff9f, 0xbf7d), (dos.date(), dos.time()));
+    assert_eq!(Ok(end), dos.to_utc());
+    assert_eq!(dos, crate::DosDateTime::from_bytes(dos.to_bytes()));
+}
+
+#[test]
+fn dos_date_time_invalid_fields() {
+    let component = |date, time| crate::DosDateTime::new(date, time).to_utc().unwrap_err().component();
+    assert_eq!(crate::Component::Month, component(0, 0));
+    assert_eq!(crate::Component::Day, component(0x0020, 0));
+    assert_eq!(crate::Component::Day, component(0x565d, 0));
+    assert_eq!(crate::Component::Hour, component(0x5829, 24 << 11));
+    assert_eq!(crate::Component::Minute, component(0x5829, 60 << 5));
+    assert_eq!(crate::Component::Second, component(0x5829, 30));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn zip_extended_timestamp() {
+    let modified = crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    let accessed = crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 59.5_f64);
+    let timestamp = crate::ExtendedTimestamp::new()
+        .with_modified(&modified).unwrap()
+        .with_accessed(&accessed).unwrap();
+    assert_eq!(Some(modified), timestamp.modified());
+    assert_eq!(Some(crate::Utc::from_ymdhms(1969, 12, 31, 23, 59, 59_f64)), timestamp.accessed());
+    assert_eq!(None, timestamp.created());
+
+    let local = timestamp.to_local_field();
+    assert_eq!(
+        vec![0x55, 0x54, 0x09, 0x00, 0x03, 0x6c, 0x72, 0x9d, 0x65, 0xff, 0xff, 0xff, 0xff],
+        local
+    );
+    assert_eq!(Some(timestamp), crate::ExtendedTimestamp::from_data(&local[4..]));
+    let central = timestamp.to_central_field();
+    assert_eq!(vec![0x55, 0x54, 0x05, 0x00, 0x03, 0x6c, 0x72, 0x9d, 0x65], central);
+    let read = crate::ExtendedTimestamp::from_data(&central[4..]).unwrap();
+    assert_eq!((Some(modified), None), (read.modified(), read.accessed()));
+    assert_eq!(None, crate::ExtendedTimestamp::from_data(&[]));
+
+    // Signed 32-bit seconds.
+    let end = crate::Utc::from_ymdhms(2038, 1, 19, 3, 14, 7_f64);
+    assert!(crate::ExtendedTimestamp::new().with_created(&end).is_ok());
+    let error = crate::ExtendedTimestamp::new()
+        .with_created(&crate::Utc::from_ymdhms(2038, 1, 19, 3, 14, 8_f64))
+        .unwrap_err();
+    assert_eq!(crate::Component::Year, error.component());
+    let start = crate::Utc::from_ymdhms(1901, 12, 13, 20, 45, 52_f64);
+    let timestamp = crate::ExtendedTimestamp::new().with_created(&start).unwrap();
+    assert_eq!(Some(start), timestamp.created());
+    assert!(crate::ExtendedTimestamp::new().with_created(&(start - core::time::Duration::from_secs(1))).is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn zip_ntfs_times() {
+    let filetime = |dt: crate::Utc| crate::FileTime::from_utc(&dt).unwrap();
+    let times = crate::NtfsTimes::new(
+        filetime(crate::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.1234567_f64)),
+        filetime(crate::Utc::from_ymd(1601, 1, 1)),
+        filetime(crate::Utc::from_ymdhms(1969, 7, 20, 20, 17, 40_f64)),
+    );
+    let field = times.to_field();
+    assert_eq!(
+        [0x0a, 0x00, 0x20, 0x00, 0, 0, 0, 0, 0x01, 0x00, 0x18, 0x00],
+        field[..12]
+    );
+    assert_eq!([0; 8], field[20..28]);
+    assert_eq!(Some(times), crate::NtfsTimes::from_data(&field[4..]));
+    assert_eq!(
+        "2024-01-09T16:21:00.123456700",
+        times.modified().to_utc().unwrap().to_rfc3339_with_nano_buf(9)
+    );
+    assert_eq!(Some(crate::Utc::from_ymdhms(1969, 7, 20, 20, 17, 40_f64)), times.created().to_utc());
+
+    // Other attributes are skipped, and truncated data rejected.
+    let mut data = vec![0, 0, 0, 0, 0x02, 0x00, 0x02, 0x00, 0xaa, 0xbb];
+    data.extend_from_slice(&field[8..]);
+    assert_eq!(Some(times), crate::NtfsTimes::from_data(&data));
+    assert_eq!(None, crate::NtfsTimes::from_data(&field[4..35]));
+    assert_eq!(None, crate::NtfsTimes::from_data(&[0, 0, 0]));
+    assert_eq!(None, crate::NtfsTimes::from_data(&[0, 0, 0, 0]));
+}
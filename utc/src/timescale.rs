@@ -0,0 +1,374 @@
+/// Nanoseconds from 1970-01-01T00:00:00 TAI to the GPS epoch,
+/// 1980-01-06T00:00:00 UTC, when TAI was 19 seconds ahead of UTC.
+const GPS_EPOCH_TAI_NANOS: i128 = (315_964_800 + 19) * 1_000_000_000;
+
+const NANOS_PER_WEEK: i128 = 7 * 86_400 * 1_000_000_000;
+
+const fn duration_from_nanos(nanos: i128) -> core::time::Duration {
+    core::time::Duration::new(
+        (nanos / 1_000_000_000) as u64,
+        (nanos % 1_000_000_000) as u32,
+    )
+}
+
+/// Writes a scale's civil date/time the way [`crate::Utc`]'s `Display`
+/// does, followed by the name of the scale.
+///
+/// Instants with no civil date between 0001 and 9999 are written as the
+/// seconds since the scale's epoch instead, e.g. `-1.500000 s TAI`.
+fn fmt_civil(
+    civil: Option<crate::Utc>, nanos: i128, scale: &str, fmtr: &mut core::fmt::Formatter,
+) -> core::fmt::Result {
+    if let Some(civil) = civil {
+        let buf = if fmtr.alternate() {
+            civil.to_iso8601_with_precision_buf(fmtr.precision().unwrap_or(3))
+        } else {
+            civil.to_rfc3339_with_nano_buf(fmtr.precision().unwrap_or(6))
+        };
+        return crate::format::pad(fmtr, buf.len() + 1 + scale.len(), |fmtr| {
+            write!(fmtr, "{buf} {scale}")
+        });
+    }
+
+    let precision = fmtr.precision().unwrap_or(if fmtr.alternate() { 3 } else { 6 }).min(9);
+    let sign = if nanos < 0 { "-" } else { "" };
+    let seconds = nanos.unsigned_abs() / 1_000_000_000;
+    let fraction = nanos.unsigned_abs() % 1_000_000_000 / 10_u128.pow(9 - precision as u32);
+    let digits = seconds.checked_ilog10().unwrap_or(0) as usize + 1;
+    let len = sign.len() + digits + if precision > 0 { precision + 1 } else { 0 } + 3 + scale.len();
+    crate::format::pad(fmtr, len, |fmtr| {
+        write!(fmtr, "{sign}{seconds}")?;
+        if precision > 0 {
+            write!(fmtr, ".{fraction:0precision$}")?;
+        }
+        write!(fmtr, " s {scale}")
+    })
+}
+
+/// An instant in International Atomic Time, which counts SI seconds
+/// without leap seconds.
+///
+/// Conversions use the [embedded](crate::LeapSeconds::embedded) leap second
+/// table unless another is given. Formatting gives the TAI date/time, which
+/// is ahead of UTC by the leap seconds so far, or the seconds since
+/// 1970-01-01T00:00:00 TAI outside of 0001 to 9999.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Tai, Utc, };
+///
+/// let leap = Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64);
+/// let tai = Tai::from_utc(&leap);
+/// assert_eq!("2017-01-01T00:00:36.000000 TAI", tai.to_string());
+/// assert_eq!("2017-01-01T00:00:36.0 TAI", format!("{tai:.1}"));
+/// assert_eq!(Some(leap), tai.to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tai {
+    /// Nanoseconds since 1970-01-01T00:00:00 TAI.
+    nanos: i128,
+}
+
+impl Tai {
+    /// Gets the instant an amount of time after 1970-01-01T00:00:00 TAI.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ Tai, Utc, };
+    ///
+    /// let tai = Tai::from_duration_since_epoch(Duration::from_secs(1_483_228_837));
+    /// assert_eq!(Some(Utc::from_ymd(2017, 1, 1)), tai.to_utc());
+    /// ```
+    pub const fn from_duration_since_epoch(duration: core::time::Duration) -> Self {
+        Self { nanos: duration.as_nanos() as i128, }
+    }
+
+    /// Gets the amount of time since 1970-01-01T00:00:00 TAI.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instant is before 1970-01-01T00:00:00 TAI.
+    pub const fn duration_since_epoch(&self) -> core::time::Duration {
+        if self.nanos < 0 {
+            panic!("Instant must be >= 1970 TAI");
+        }
+
+        duration_from_nanos(self.nanos)
+    }
+
+    /// Gets the instant a number of nanoseconds from 1970-01-01T00:00:00
+    /// TAI, negative before it.
+    pub(crate) const fn from_nanos_since_epoch(nanos: i128) -> Self {
+        Self { nanos, }
+    }
+
+    /// Gets the number of nanoseconds since 1970-01-01T00:00:00 TAI,
+    /// negative before it.
+    pub(crate) const fn nanos_since_epoch(&self) -> i128 {
+        self.nanos
+    }
+
+    /// Gets the instant of a UTC date/time.
+    pub fn from_utc(dt: &crate::Utc) -> Self {
+        Self::from_utc_with(dt, &crate::LeapSeconds::embedded())
+    }
+
+    /// Gets the instant of a UTC date/time using a leap second table.
+    pub fn from_utc_with(dt: &crate::Utc, leap_seconds: &crate::LeapSeconds) -> Self {
+        Self { nanos: leap_seconds.tai_nanos(dt), }
+    }
+
+    /// Gets the UTC date/time, or `None` if it is outside of [`Utc::MIN`]
+    /// and [`Utc::MAX`].
+    ///
+    /// [`Utc::MIN`]: crate::Utc::MIN
+    /// [`Utc::MAX`]: crate::Utc::MAX
+    pub fn to_utc(&self) -> Option<crate::Utc> {
+        self.to_utc_with(&crate::LeapSeconds::embedded())
+    }
+
+    /// Gets the UTC date/time using a leap second table.
+    pub fn to_utc_with(&self, leap_seconds: &crate::LeapSeconds) -> Option<crate::Utc> {
+        leap_seconds.utc_from_tai_nanos(self.nanos)
+    }
+
+    /// Gets the instant of a GPS time, which is always 19 seconds behind TAI.
+    pub const fn from_gps(gps: &Gps) -> Self {
+        Self { nanos: gps.nanos + GPS_EPOCH_TAI_NANOS, }
+    }
+
+    /// Gets the GPS time, or `None` if it is before the GPS epoch.
+    pub const fn to_gps(&self) -> Option<Gps> {
+        if self.nanos < GPS_EPOCH_TAI_NANOS {
+            return None;
+        }
+
+        Some(Gps { nanos: self.nanos - GPS_EPOCH_TAI_NANOS, })
+    }
+
+    /// Gets the TAI date/time, written with the same fields as a UTC one.
+    pub fn civil(&self) -> Option<crate::Utc> {
+        crate::Utc::from_nanos_since_epoch(self.nanos)
+    }
+}
+
+impl From<crate::Utc> for Tai {
+    fn from(dt: crate::Utc) -> Self {
+        Self::from_utc(&dt)
+    }
+}
+
+impl From<Gps> for Tai {
+    fn from(gps: Gps) -> Self {
+        Self::from_gps(&gps)
+    }
+}
+
+impl TryFrom<Tai> for crate::Utc {
+    type Error = crate::RangeError;
+
+    fn try_from(tai: Tai) -> Result<Self, Self::Error> {
+        tai.to_utc().ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+/// Formats like [`crate::Utc`], followed by ` TAI`.
+impl core::fmt::Display for Tai {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_civil(self.civil(), self.nanos, "TAI", fmtr)
+    }
+}
+
+/// An instant in GPS time, which counts SI seconds without leap seconds
+/// from 1980-01-06T00:00:00 UTC.
+///
+/// GPS time is a fixed 19 seconds behind TAI, so it is ahead of UTC by the
+/// leap seconds since 1980. Formatting gives the GPS date/time, or the
+/// seconds since the GPS epoch after 9999.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use utc::{ Gps, Utc, };
+///
+/// let gps = Gps::from_utc(&Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64)).unwrap();
+/// assert_eq!(2296, gps.week());
+/// assert_eq!(Duration::from_secs(231_678), gps.time_of_week());
+/// assert_eq!("2024-01-09T16:21:18.000000 GPS", gps.to_string());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gps {
+    /// Nanoseconds since the GPS epoch.
+    nanos: i128,
+}
+
+impl Gps {
+    /// The GPS epoch, 1980-01-06T00:00:00 UTC.
+    pub const EPOCH: Self = Self { nanos: 0, };
+
+    /// Gets the instant an amount of time after the GPS epoch.
+    pub const fn from_duration_since_epoch(duration: core::time::Duration) -> Self {
+        Self { nanos: duration.as_nanos() as i128, }
+    }
+
+    /// Gets the amount of time since the GPS epoch.
+    pub const fn duration_since_epoch(&self) -> core::time::Duration {
+        duration_from_nanos(self.nanos)
+    }
+
+    /// Gets the instant from a full week number and the time into the week.
+    /// Gets `None` if the time of week is a week or longer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ Gps, Utc, };
+    ///
+    /// let gps = Gps::from_week(2296, Duration::from_secs(231_678)).unwrap();
+    /// assert_eq!(Some(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64)), gps.to_utc());
+    /// assert_eq!(None, Gps::from_week(2296, Duration::from_secs(604_800)));
+    /// ```
+    pub const fn from_week(week: u32, time_of_week: core::time::Duration) -> Option<Self> {
+        let time_of_week = time_of_week.as_nanos() as i128;
+        if time_of_week >= NANOS_PER_WEEK {
+            return None;
+        }
+
+        Some(Self { nanos: week as i128 * NANOS_PER_WEEK + time_of_week, })
+    }
+
+    /// Gets the instant from a week number which has rolled over, such as
+    /// the 10 bit week of the legacy navigation message which rolls over
+    /// every 1024 weeks, choosing the full week nearest to the reference.
+    ///
+    /// Gets `None` if the time of week is a week or longer or the rollover
+    /// is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use utc::{ Gps, Utc, };
+    ///
+    /// // 2296 weeks is 248 weeks into the third 1024 week era.
+    /// let reference = Utc::from_ymd(2024, 1, 1);
+    /// let gps = Gps::from_rolled_over_week(248, Duration::from_secs(231_678), 1024, &reference)
+    ///     .unwrap();
+    /// assert_eq!(2296, gps.week());
+    /// ```
+    pub fn from_rolled_over_week(
+        week: u32, time_of_week: core::time::Duration, rollover: u32, reference: &crate::Utc,
+    ) -> Option<Self> {
+        if rollover == 0 {
+            return None;
+        }
+
+        let reference = Tai::from_utc(reference).nanos - GPS_EPOCH_TAI_NANOS;
+        let rollover_nanos = rollover as i128 * NANOS_PER_WEEK;
+        let truncated = Self::from_week(week % rollover, time_of_week)?.nanos;
+
+        // The candidate in the reference's era, or the one before or after.
+        let era = (reference - truncated).div_euclid(rollover_nanos);
+        let nearest = [era, era + 1].into_iter()
+            .map(|era| truncated + era * rollover_nanos)
+            .filter(|nanos| *nanos >= 0)
+            .min_by_key(|nanos| (nanos - reference).abs())?;
+        Some(Self { nanos: nearest, })
+    }
+
+    /// Gets the number of whole weeks since the GPS epoch.
+    pub const fn week(&self) -> u32 {
+        (self.nanos / NANOS_PER_WEEK) as u32
+    }
+
+    /// Gets the week number modulo a rollover, such as 1024 for the 10 bit
+    /// week of the legacy navigation message or 8192 for the 13 bit week of
+    /// CNAV.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rollover is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let gps = utc::Gps::from_week(2296, Duration::ZERO).unwrap();
+    /// assert_eq!(248, gps.rolled_over_week(1024));
+    /// assert_eq!(2296, gps.rolled_over_week(8192));
+    /// ```
+    pub const fn rolled_over_week(&self, rollover: u32) -> u32 {
+        self.week() % rollover
+    }
+
+    /// Gets the amount of time since the start of the week, at midnight
+    /// between Saturday and Sunday.
+    pub const fn time_of_week(&self) -> core::time::Duration {
+        duration_from_nanos(self.nanos % NANOS_PER_WEEK)
+    }
+
+    /// Gets the GPS time of a UTC date/time, or `None` if it is before the
+    /// GPS epoch.
+    pub fn from_utc(dt: &crate::Utc) -> Option<Self> {
+        Tai::from_utc(dt).to_gps()
+    }
+
+    /// Gets the GPS time of a UTC date/time using a leap second table.
+    pub fn from_utc_with(dt: &crate::Utc, leap_seconds: &crate::LeapSeconds) -> Option<Self> {
+        Tai::from_utc_with(dt, leap_seconds).to_gps()
+    }
+
+    /// Gets the UTC date/time, or `None` if it is after [`Utc::MAX`].
+    ///
+    /// [`Utc::MAX`]: crate::Utc::MAX
+    pub fn to_utc(&self) -> Option<crate::Utc> {
+        Tai::from_gps(self).to_utc()
+    }
+
+    /// Gets the UTC date/time using a leap second table.
+    pub fn to_utc_with(&self, leap_seconds: &crate::LeapSeconds) -> Option<crate::Utc> {
+        Tai::from_gps(self).to_utc_with(leap_seconds)
+    }
+
+    /// Gets the GPS date/time, written with the same fields as a UTC one.
+    pub fn civil(&self) -> Option<crate::Utc> {
+        crate::Utc::from_nanos_since_epoch(self.nanos + GPS_EPOCH_TAI_NANOS - 19 * 1_000_000_000)
+    }
+}
+
+impl TryFrom<Tai> for Gps {
+    type Error = crate::RangeError;
+
+    fn try_from(tai: Tai) -> Result<Self, Self::Error> {
+        tai.to_gps().ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+impl TryFrom<crate::Utc> for Gps {
+    type Error = crate::RangeError;
+
+    fn try_from(dt: crate::Utc) -> Result<Self, Self::Error> {
+        Self::from_utc(&dt).ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+impl TryFrom<Gps> for crate::Utc {
+    type Error = crate::RangeError;
+
+    fn try_from(gps: Gps) -> Result<Self, Self::Error> {
+        gps.to_utc().ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+/// Formats like [`crate::Utc`], followed by ` GPS`.
+impl core::fmt::Display for Gps {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmt_civil(self.civil(), self.nanos, "GPS", fmtr)
+    }
+}
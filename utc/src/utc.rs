@@ -12,54 +12,120 @@ pub struct Utc {
     nano: f64,
 }
 
+/// The number of seconds from the unix epoch to 0001-01-01T00:00:00, the
+/// first date/time which can be represented.
+const START_SECONDS: i64 = super::statics::days_from_civil(
+    super::statics::MIN_Y as i64, 1, 1,
+) * 86_400;
+
+/// The number of seconds from the unix epoch to 10000-01-01T00:00:00, the
+/// first date/time which cannot be represented.
+const END_SECONDS: i64 = super::statics::days_from_civil(
+    super::statics::MAX_Y as i64 + 1, 1, 1,
+) * 86_400;
+
 impl Utc {
+    /// The unix epoch, 1970-01-01T00:00:00.
+    pub const EPOCH: Utc = Utc::from_epoch_parts(0, 0_f64);
+
+    /// The earliest date/time which can be represented,
+    /// 0001-01-01T00:00:00 in the proleptic Gregorian calendar.
+    pub const MIN: Utc = Utc::from_epoch_parts(START_SECONDS, 0_f64);
+
+    /// The latest date/time which can be represented,
+    /// 9999-12-31T23:59:59.999999999.
+    pub const MAX: Utc = Utc::from_epoch_parts(END_SECONDS - 1, 0.999_999_999_f64);
+
     /// Gets the year part.
-    pub fn year(&self) -> usize {
+    pub const fn year(&self) -> usize {
         self.year
     }
 
     /// Gets the month part.
-    pub fn month(&self) -> usize {
+    pub const fn month(&self) -> usize {
         self.month
     }
 
     /// Gets the name of the month.
-    pub fn month_of_year(&self) -> &'static str {
+    pub const fn month_of_year(&self) -> &'static str {
         self.month_of_year
     }
 
     /// Gets the day part.
-    pub fn day(&self)-> usize {
+    pub const fn day(&self)-> usize {
         self.day
     }
 
     /// Gets the name of the day.
-    pub fn day_of_week(&self) -> &'static str {
+    pub const fn day_of_week(&self) -> &'static str {
         self.day_of_week
     }
 
+    /// Gets the day of the week.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymd(2024, 1, 9);
+    /// assert_eq!(utc::Weekday::Tuesday, dt.weekday());
+    /// ```
+    pub fn weekday(&self) -> crate::Weekday {
+        crate::Weekday::from_days_since_epoch(self.days_since_epoch())
+    }
+
     /// Gets the hour part.
-    pub fn hour(&self) -> usize {
+    pub const fn hour(&self) -> usize {
         self.hour
     }
 
     /// Gets the minute part.
-    pub fn minute(&self) -> usize {
+    pub const fn minute(&self) -> usize {
         self.minute
     }
 
     /// Gets the second part without nano seconds.
-    pub fn second(&self) -> usize {
+    pub const fn second(&self) -> usize {
         self.second
     }
 
     /// Gets the fractional nano seconds.
-    pub fn nano(&self) -> f64 {
+    pub const fn nano(&self) -> f64 {
         self.nano
     }
 
+    /// Gets the fractional seconds in whole nanoseconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.25_f64);
+    /// assert_eq!(250_000_000, dt.nanosecond());
+    /// ```
+    pub fn nanosecond(&self) -> u32 {
+        (super::statics::round(self.nano * 1_000_000_000_f64) as u32).min(999_999_999)
+    }
+
+    /// Gets the date/time with the fractional seconds set to a number of
+    /// nanoseconds, or `None` if it is a second or more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// const DAY: utc::Utc = utc::Utc::from_ymd_const(2024, 1, 9);
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 0, 0, 0.5_f64);
+    /// assert_eq!(Some(dt), DAY.with_nanosecond(500_000_000));
+    /// assert_eq!(None, DAY.with_nanosecond(1_000_000_000));
+    /// ```
+    pub const fn with_nanosecond(&self, nanosecond: u32) -> Option<Self> {
+        if nanosecond >= 1_000_000_000 {
+            return None;
+        }
+
+        Some(Self { nano: nanosecond as f64 / 1_000_000_000_f64, ..*self })
+    }
+
     /// Gets the seconds with fractional nano seconds.
-    pub fn second_with_nano(&self) -> f64 {
+    pub const fn second_with_nano(&self) -> f64 {
         self.second as f64 + self.nano
     }
 
@@ -72,7 +138,7 @@ impl Utc {
     /// assert!(dt.is_leap_year());
     /// ```
     pub fn is_leap_year(&self) -> bool {
-        crate::statics::ly(self.year) == 1
+        crate::calendar::is_leap_year(self.year)
     }
 
     /// Gets the date/time in yyyy-MM-ddTHH:mm:ss format.
@@ -83,11 +149,57 @@ impl Utc {
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
     /// assert_eq!("2024-01-09T16:21:00", dt.as_rfc3339());
     /// ```
-    pub fn as_rfc3339(&self) -> String {
-        format!(
-            "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}",
-            self.year, self.month, self.day, self.hour, self.minute, self.second
-        )
+    #[cfg(feature = "alloc")]
+    pub fn as_rfc3339(&self) -> alloc::string::String {
+        self.to_rfc3339_buf().as_str().into()
+    }
+
+    /// Gets the date/time in yyyy-MM-ddTHH:mm:ss format without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// assert_eq!("2024-01-09T16:21:00", dt.to_rfc3339_buf());
+    /// ```
+    pub fn to_rfc3339_buf(&self) -> crate::FormatBuf {
+        let mut buf = crate::FormatBuf::new();
+        buf.push_date(self);
+        buf.push_time(self, b'T');
+        buf
+    }
+
+    /// Writes the date/time in yyyy-MM-ddTHH:mm:ss format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// let mut s = String::new();
+    /// dt.write_rfc3339(&mut s).unwrap();
+    /// assert_eq!("2024-01-09T16:21:00", s);
+    /// ```
+    pub fn write_rfc3339(
+        &self, writer: &mut impl core::fmt::Write,
+    ) -> core::fmt::Result {
+        writer.write_str(&self.to_rfc3339_buf())
+    }
+
+    /// Writes the date/time in yyyy-MM-ddTHH:mm:ss format to a byte stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// let mut bytes = Vec::new();
+    /// dt.write_rfc3339_io(&mut bytes).unwrap();
+    /// assert_eq!(b"2024-01-09T16:21:00", bytes.as_slice());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_rfc3339_io(
+        &self, writer: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        writer.write_all(self.to_rfc3339_buf().as_bytes())
     }
 
     /// Gets the date/time in yyyy-MM-dd format.
@@ -98,28 +210,123 @@ impl Utc {
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
     /// assert_eq!("2024-01-09", dt.as_ymd());
     /// ```
-    pub fn as_ymd(&self) -> String {
-        format!(
-            "{:0>4}-{:0>2}-{:0>2}",
-            self.year, self.month, self.day
-        )
+    #[cfg(feature = "alloc")]
+    pub fn as_ymd(&self) -> alloc::string::String {
+        self.to_ymd_buf().as_str().into()
+    }
+
+    /// Gets the date/time in yyyy-MM-dd format without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// assert_eq!("2024-01-09", dt.to_ymd_buf());
+    /// ```
+    pub fn to_ymd_buf(&self) -> crate::FormatBuf {
+        let mut buf = crate::FormatBuf::new();
+        buf.push_date(self);
+        buf
+    }
+
+    /// Writes the date/time in yyyy-MM-dd format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// let mut s = String::new();
+    /// dt.write_ymd(&mut s).unwrap();
+    /// assert_eq!("2024-01-09", s);
+    /// ```
+    pub fn write_ymd(
+        &self, writer: &mut impl core::fmt::Write,
+    ) -> core::fmt::Result {
+        writer.write_str(&self.to_ymd_buf())
+    }
+
+    /// Writes the date/time in yyyy-MM-dd format to a byte stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+    /// let mut bytes = Vec::new();
+    /// dt.write_ymd_io(&mut bytes).unwrap();
+    /// assert_eq!(b"2024-01-09", bytes.as_slice());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_ymd_io(
+        &self, writer: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        writer.write_all(self.to_ymd_buf().as_bytes())
     }
 
     /// Gets the date/time in yyyy-MM-ddTHH:mm:ss.f format with the
     /// specified precision.
     ///
+    /// The fraction is truncated, and zero beyond nanoseconds. A precision
+    /// of zero omits the fraction.
+    ///
     /// # Examples
     ///
     /// ```
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 59, 9.8_f64);
     /// assert_eq!("2024-01-09T12:59:09.8", dt.as_rfc3339_with_nano(1));
     /// ```
-    pub fn as_rfc3339_with_nano(&self, precision: usize) -> String {
-        let nano = &format!("{:.precision$}", self.nano)[2..];
-        format!(
-            "{:0>4}-{:0>2}-{:0>2}T{:0>2}:{:0>2}:{:0>2}.{nano}",
-            self.year, self.month, self.day, self.hour, self.minute, self.second
-        )
+    #[cfg(feature = "alloc")]
+    pub fn as_rfc3339_with_nano(&self, precision: usize) -> alloc::string::String {
+        alloc::format!("{}{}", self.to_rfc3339_buf(), crate::format::Fraction::new(self, precision))
+    }
+
+    /// Gets the date/time in yyyy-MM-ddTHH:mm:ss.f format without allocating,
+    /// with at most nine fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 59, 9.8_f64);
+    /// assert_eq!("2024-01-09T12:59:09.8", dt.to_rfc3339_with_nano_buf(1));
+    /// ```
+    pub fn to_rfc3339_with_nano_buf(&self, precision: usize) -> crate::FormatBuf {
+        let mut buf = crate::FormatBuf::new();
+        buf.push_date(self);
+        buf.push_time(self, b'T');
+        buf.push_fraction(self, precision);
+        buf
+    }
+
+    /// Writes the date/time in yyyy-MM-ddTHH:mm:ss.f format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 59, 9.8_f64);
+    /// let mut s = String::new();
+    /// dt.write_rfc3339_with_nano(&mut s, 1).unwrap();
+    /// assert_eq!("2024-01-09T12:59:09.8", s);
+    /// ```
+    pub fn write_rfc3339_with_nano(
+        &self, writer: &mut impl core::fmt::Write, precision: usize,
+    ) -> core::fmt::Result {
+        write!(writer, "{}{}", self.to_rfc3339_buf(), crate::format::Fraction::new(self, precision))
+    }
+
+    /// Writes the date/time in yyyy-MM-ddTHH:mm:ss.f format to a byte stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 59, 9.8_f64);
+    /// let mut bytes = Vec::new();
+    /// dt.write_rfc3339_with_nano_io(&mut bytes, 1).unwrap();
+    /// assert_eq!(b"2024-01-09T12:59:09.8", bytes.as_slice());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_rfc3339_with_nano_io(
+        &self, writer: &mut impl std::io::Write, precision: usize,
+    ) -> std::io::Result<()> {
+        write!(writer, "{}{}", self.to_rfc3339_buf(), crate::format::Fraction::new(self, precision))
     }
 
     /// Gets the date/time/ in yyyy-MM-ddTHH:mm:ss.ffffff format.
@@ -130,24 +337,84 @@ impl Utc {
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.2_f64);
     /// assert_eq!("2024-01-09T12:05:57.200000", dt.as_rfc3339_nano());
     /// ```
-    pub fn as_rfc3339_nano(&self) -> String {
+    #[cfg(feature = "alloc")]
+    pub fn as_rfc3339_nano(&self) -> alloc::string::String {
         self.as_rfc3339_with_nano(6)
     }
 
     /// Gets the date/time/ in yyyy-MM-dd HH:mm:ss.f format with the given
     /// nanosecond precision.
     ///
+    /// The fraction is truncated, and zero beyond nanoseconds. A precision
+    /// of zero omits the fraction.
+    ///
     /// # Examples
     ///
     /// ```
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.2_f64);
     /// assert_eq!("2024-01-09 12:05:57.200", dt.as_iso8601_with_precision(3));
     /// ```
-    pub fn as_iso8601_with_precision(&self, precision: usize) -> String {
-        let nano = &format!("{:.precision$}", self.nano)[2..];
-        format!(
-            "{:0>4}-{:0>2}-{:0>2} {:0>2}:{:0>2}:{:0>2}.{nano}",
-            self.year, self.month, self.day, self.hour, self.minute, self.second
+    #[cfg(feature = "alloc")]
+    pub fn as_iso8601_with_precision(&self, precision: usize) -> alloc::string::String {
+        alloc::format!(
+            "{}{}",
+            self.to_iso8601_with_precision_buf(0), crate::format::Fraction::new(self, precision)
+        )
+    }
+
+    /// Gets the date/time in yyyy-MM-dd HH:mm:ss.f format without allocating,
+    /// with at most nine fractional digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.2_f64);
+    /// assert_eq!("2024-01-09 12:05:57.200", dt.to_iso8601_with_precision_buf(3));
+    /// ```
+    pub fn to_iso8601_with_precision_buf(&self, precision: usize) -> crate::FormatBuf {
+        let mut buf = crate::FormatBuf::new();
+        buf.push_date(self);
+        buf.push_time(self, b' ');
+        buf.push_fraction(self, precision);
+        buf
+    }
+
+    /// Writes the date/time in yyyy-MM-dd HH:mm:ss.f format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.2_f64);
+    /// let mut s = String::new();
+    /// dt.write_iso8601_with_precision(&mut s, 3).unwrap();
+    /// assert_eq!("2024-01-09 12:05:57.200", s);
+    /// ```
+    pub fn write_iso8601_with_precision(
+        &self, writer: &mut impl core::fmt::Write, precision: usize,
+    ) -> core::fmt::Result {
+        write!(
+            writer, "{}{}",
+            self.to_iso8601_with_precision_buf(0), crate::format::Fraction::new(self, precision)
+        )
+    }
+
+    /// Writes the date/time in yyyy-MM-dd HH:mm:ss.f format to a byte stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.2_f64);
+    /// let mut bytes = Vec::new();
+    /// dt.write_iso8601_with_precision_io(&mut bytes, 3).unwrap();
+    /// assert_eq!(b"2024-01-09 12:05:57.200", bytes.as_slice());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_iso8601_with_precision_io(
+        &self, writer: &mut impl std::io::Write, precision: usize,
+    ) -> std::io::Result<()> {
+        write!(
+            writer, "{}{}",
+            self.to_iso8601_with_precision_buf(0), crate::format::Fraction::new(self, precision)
         )
     }
 
@@ -159,7 +426,8 @@ impl Utc {
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.2_f64);
     /// assert_eq!("2024-01-09 12:05:57.200", dt.as_iso8601());
     /// ```
-    pub fn as_iso8601(&self) -> String {
+    #[cfg(feature = "alloc")]
+    pub fn as_iso8601(&self) -> alloc::string::String {
         self.as_iso8601_with_precision(3)
     }
 
@@ -171,60 +439,111 @@ impl Utc {
     /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57_f64);
     /// assert_eq!("Tue, 09 Jan 2024 12:05:57 GMT", dt.as_rfc7231());
     /// ```
-    pub fn as_rfc7231(&self) -> String {
-        format!(
-            "{}, {:0>2} {} {:0>4} {:0>2}:{:0>2}:{:0>2} GMT",
-            &self.day_of_week[0..3], self.day, &self.month_of_year[0..3],
-            self.year, self.hour, self.minute, self.second
-        )
+    #[cfg(feature = "alloc")]
+    pub fn as_rfc7231(&self) -> alloc::string::String {
+        self.to_rfc7231_buf().as_str().into()
+    }
+
+    /// Gets the date/time in Day, dd Mon yyyy HH:mm:ss Z format without allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57_f64);
+    /// assert_eq!("Tue, 09 Jan 2024 12:05:57 GMT", dt.to_rfc7231_buf());
+    /// ```
+    pub fn to_rfc7231_buf(&self) -> crate::FormatBuf {
+        let mut buf = crate::FormatBuf::new();
+        buf.push_rfc7231(self);
+        buf
+    }
+
+    /// Writes the date/time in Day, dd Mon yyyy HH:mm:ss Z format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57_f64);
+    /// let mut s = String::new();
+    /// dt.write_rfc7231(&mut s).unwrap();
+    /// assert_eq!("Tue, 09 Jan 2024 12:05:57 GMT", s);
+    /// ```
+    pub fn write_rfc7231(
+        &self, writer: &mut impl core::fmt::Write,
+    ) -> core::fmt::Result {
+        writer.write_str(&self.to_rfc7231_buf())
+    }
+
+    /// Writes the date/time in Day, dd Mon yyyy HH:mm:ss Z format to a byte stream.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57_f64);
+    /// let mut bytes = Vec::new();
+    /// dt.write_rfc7231_io(&mut bytes).unwrap();
+    /// assert_eq!(b"Tue, 09 Jan 2024 12:05:57 GMT", bytes.as_slice());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn write_rfc7231_io(
+        &self, writer: &mut impl std::io::Write,
+    ) -> std::io::Result<()> {
+        writer.write_all(self.to_rfc7231_buf().as_bytes())
     }
 
+    #[cfg(feature = "std")]
     fn from_seconds_since_epoch(seconds_with_nanos: f64) -> Self {
-        let total_seconds_since_epoch = seconds_with_nanos as usize;
+        let total_seconds_since_epoch = seconds_with_nanos as i64;
         let nano = seconds_with_nanos - total_seconds_since_epoch as f64;
-        let mut minute = total_seconds_since_epoch / 60_usize;
-        let second = total_seconds_since_epoch - (minute * 60_usize);
-        let mut hour = minute / 60_usize;
-        minute -= hour * 60_usize;
-        let mut days = hour / 24_usize;
-        hour -= days * 24_usize;
-
-        let day_of_week = super::statics::D[days % super::statics::DIW];
-
-        let mut month = super::statics::EPOCH_M;
-        let mut year = super::statics::EPOCH_Y;
-
-        let day;
-
-        loop {
-            let ly = super::statics::ly(year);
-
-            let days_in_year = super::statics::DIY[ly];
-            if days > days_in_year {
-                days -= days_in_year;
-                year += 1;
-                continue;
-            }
-
-            let days_in_month = super::statics::DIM[month - 1][ly];
-            if days >= days_in_month {
-                days -= days_in_month;
-                month += 1;
-                if month > 12 {
-                    month = 1;
-                    year += 1;
-                }
-                continue;
-            }
-
-            day = days + super::statics::EPOCH_D;
-            break;
+        Self::from_epoch_parts(total_seconds_since_epoch, nano)
+    }
+
+    /// Gets the date/time for a number of nanoseconds since the unix epoch,
+    /// negative before it, or `None` if it is out of range.
+    pub(crate) const fn from_nanos_since_epoch(nanos: i128) -> Option<Self> {
+        let seconds = nanos.div_euclid(1_000_000_000);
+        if seconds < START_SECONDS as i128 || seconds >= END_SECONDS as i128 {
+            return None;
         }
 
+        Some(Self::from_epoch_parts(
+            seconds as i64,
+            nanos.rem_euclid(1_000_000_000) as f64 / 1_000_000_000_f64,
+        ))
+    }
+
+    /// Gets the leap second following this date/time's 23:59:59, without
+    /// checking that there is one.
+    pub(crate) const fn leap_second_after(&self, nano: f64) -> Self {
+        Self { hour: 23, minute: 59, second: 60, nano, ..*self }
+    }
+
+    /// Gets the date/time on the same date at a number of seconds into the
+    /// day, without working out the date again.
+    #[cfg(feature = "std")]
+    pub(crate) const fn with_seconds_of_day(&self, seconds: usize, nano: f64) -> Self {
+        Self {
+            hour: seconds / 3_600,
+            minute: seconds % 3_600 / 60,
+            second: seconds % 60,
+            nano,
+            ..*self
+        }
+    }
+
+    const fn from_epoch_parts(total_seconds_since_epoch: i64, nano: f64) -> Self {
+        let days = total_seconds_since_epoch.div_euclid(86_400);
+        let seconds_of_day = total_seconds_since_epoch.rem_euclid(86_400) as usize;
+        let hour = seconds_of_day / 3_600;
+        let minute = seconds_of_day % 3_600 / 60;
+        let second = seconds_of_day % 60;
+
+        let day_of_week = super::statics::D[days.rem_euclid(super::statics::DIW as i64) as usize];
+        let (year, month, day) = super::statics::civil_from_days(days);
         let month_of_year = super::statics::M[month - 1];
 
         Self {
-            year,
+            year: year as usize,
             month,
             month_of_year,
             day,
@@ -236,7 +555,14 @@ impl Utc {
         }
     }
 
-    /// Gets the specified date/time.
+    /// Gets the specified date/time, carrying any out of range components
+    /// into the next larger one.
+    ///
+    /// See [`Utc::from_ymdhms_signed`] for how components are normalized.
+    /// A second of 60 at 23:59 is kept as a leap second, rather than carried,
+    /// on days which end with one in the
+    /// [embedded table](crate::LeapSeconds::embedded), or in another with
+    /// [`Utc::from_ymdhms_with`].
     ///
     /// # Examples
     ///
@@ -249,110 +575,292 @@ impl Utc {
     /// assert_eq!(57, dt.minute());
     /// assert_eq!(1, dt.second());
     /// assert_eq!(0_f64, dt.nano());
+    ///
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 1, 0, 0, 120.0_f64);
+    /// assert_eq!("2024-01-01T00:02:00", dt.to_rfc3339_buf());
+    ///
+    /// let dt = utc::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60.5_f64);
+    /// assert_eq!("2016-12-31T23:59:60.500000", dt.to_rfc3339_with_nano_buf(6));
+    ///
+    /// let dt = utc::Utc::from_ymdhms(2017, 12, 31, 23, 59, 60_f64);
+    /// assert_eq!("2018-01-01T00:00:00", dt.to_rfc3339_buf());
     /// ```
-    pub fn from_ymdhms(
-        mut year: usize, mut month: usize, mut day: usize,
-        mut hour: usize, mut minute: usize, second_n: f64,
+    pub const fn from_ymdhms(
+        year: usize, month: usize, day: usize,
+        hour: usize, minute: usize, second: f64,
     ) -> Self {
-        // only allow >= epoch
-        if year < 1970 || year == 1970 && month == 0 {
-            panic!("Date must be >= 1970");
-        }
+        Self::from_ymdhms_signed(
+            year as i64, month as i64, day as i64,
+            hour as i64, minute as i64, second,
+        )
+    }
 
-        // Correct possible zeros
-        if day == 0 && month == 0 {
-            year -= 1;
-            month = 11;
-            day = super::statics::DIM[month - 1][super::statics::ly(year)];
-        } else if month == 0 {
-            month = 12;
-            year -= 1;
-        } else if day == 0 {
-            if month == 1 {
-                month = 12;
-                year -= 1;
-            } else {
-                month -= 1;
-            }
-            day = super::statics::DIM[month - 1][super::statics::ly(year)];
-        }
+    /// Gets the specified date/time as [`Utc::from_ymdhms`] does, but keeps a
+    /// second of 60 at 23:59 as a leap second on days which end with one in
+    /// the given table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, Utc, };
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let table = LeapSeconds::parse("3692217600 37\n4307472000 38").unwrap();
+    /// let dt = Utc::from_ymdhms_with(2036, 6, 30, 23, 59, 60_f64, &table);
+    /// assert!(dt.is_leap_second());
+    /// assert!(!Utc::from_ymdhms(2036, 6, 30, 23, 59, 60_f64).is_leap_second());
+    /// # }
+    /// ```
+    pub fn from_ymdhms_with(
+        year: usize, month: usize, day: usize,
+        hour: usize, minute: usize, second: f64,
+        leap_seconds: &crate::LeapSeconds,
+    ) -> Self {
+        Self::from_ymdhms_signed_with(
+            year as i64, month as i64, day as i64,
+            hour as i64, minute as i64, second,
+            leap_seconds,
+        )
+    }
 
-        // only allow >= epoch
-        if year < 1970 {
-            panic!("Date must be >= 1970");
-        }
+    /// Gets the specified date/time from components of any sign and
+    /// magnitude.
+    ///
+    /// Months are counted from January of the given year and days from the
+    /// first of the resulting month, so month `0` is December of the
+    /// previous year and day `0` is the last day of the previous month.
+    /// Hours, minutes and seconds are then added to the start of that day.
+    /// Each carry and borrow is exact. A second of 60 at 23:59 is kept as a
+    /// leap second on days which end with one in the
+    /// [embedded table](crate::LeapSeconds::embedded).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the second is not finite or the date/time is outside of
+    /// [`Utc::MIN`] and [`Utc::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms_signed(2024, -3, 1, 0, 0, 0_f64);
+    /// assert_eq!("2023-09-01T00:00:00", dt.to_rfc3339_buf());
+    ///
+    /// let dt = utc::Utc::from_ymdhms_signed(2024, 3, -45, 1000, 0, 0_f64);
+    /// assert_eq!("2024-02-25T16:00:00", dt.to_rfc3339_buf());
+    ///
+    /// let dt = utc::Utc::from_ymdhms_signed(2024, 1, 1, 0, -1, -0.25_f64);
+    /// assert_eq!("2023-12-31T23:58:59.750000", dt.to_rfc3339_with_nano_buf(6));
+    /// ```
+    pub const fn from_ymdhms_signed(
+        year: i64, month: i64, day: i64,
+        hour: i64, minute: i64, second: f64,
+    ) -> Self {
+        let leap_day = year >= 0 && month >= 0 && day >= 0 &&
+            super::leap::is_embedded_leap_day(year as usize, month as usize, day as usize);
+        Self::from_signed_parts(year, month, day, hour, minute, second, leap_day)
+    }
 
-        let mut second = second_n as usize;
-        let mut nano = second_n - second as f64;
+    /// Gets the specified date/time as [`Utc::from_ymdhms_signed`] does, but
+    /// keeps a second of 60 at 23:59 as a leap second on days which end with
+    /// one in the given table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the second is not finite or the date/time is outside of
+    /// [`Utc::MIN`] and [`Utc::MAX`].
+    pub fn from_ymdhms_signed_with(
+        year: i64, month: i64, day: i64,
+        hour: i64, minute: i64, second: f64,
+        leap_seconds: &crate::LeapSeconds,
+    ) -> Self {
+        let leap_day = year >= 0 && month >= 0 && day >= 0 &&
+            leap_seconds.has_leap_second(year as usize, month as usize, day as usize);
+        Self::from_signed_parts(year, month, day, hour, minute, second, leap_day)
+    }
 
-        // Correct overflows from bottom-up.
-        // Overflow nanos into seconds.
-        if nano > 1_f64 {
-            let overflow = (nano - ((nano as usize) as f64)) as usize;
-            second += overflow;
-            nano -= overflow as f64;
+    /// Normalizes the components, keeping a second of 60 at 23:59 as a leap
+    /// second if the day ends with one.
+    const fn from_signed_parts(
+        year: i64, month: i64, day: i64,
+        hour: i64, minute: i64, second: f64,
+        leap_day: bool,
+    ) -> Self {
+        if !second.is_finite() {
+            panic!("Second must be finite");
         }
 
-        // Overflow seconds into minutes.
-        if second >= 60 {
-            minute += (second % 60) + 1;
-            second %= 60;
+        // A leap second is kept as 23:59:60 rather than carried.
+        if hour == 23 && minute == 59 && second >= 60_f64 && second < 61_f64 && leap_day {
+            return Self::from_signed_parts(year, month, day, 23, 59, 59_f64, false)
+                .leap_second_after(second - 60_f64);
         }
 
-        // Overflow minutes into hours.
-        if minute >= 60 {
-            hour += (minute % 60) + 1;
-            minute %= 60;
-        }
+        // Carry months into years.
+        let months = year as i128 * super::statics::MIY as i128 + month as i128 - 1;
+        let year = months.div_euclid(super::statics::MIY as i128) as i64;
+        let month = months.rem_euclid(super::statics::MIY as i128) as usize + 1;
+
+        // Everything smaller is an offset from the start of the month. The
+        // calendar repeats every 400 years, so only the year within its era
+        // goes through `days_from_civil`, which could overflow otherwise.
+        let whole_seconds = super::statics::floor(second);
+        let nano = second - whole_seconds;
+        let eras = (year as i128).div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let days = eras * 146_097 +
+            super::statics::days_from_civil(year_of_era, month, 1) as i128 + day as i128 - 1;
+        let seconds = (days * 86_400 + hour as i128 * 3_600 + minute as i128 * 60)
+            .saturating_add(whole_seconds as i128);
 
-        // Overflow hours into days.
-        if hour >= 24 {
-            day += (hour % 24) + 1;
-            hour %= 24;
+        if seconds < START_SECONDS as i128 {
+            panic!("Date must be >= 0001");
         }
 
-        // Overflow months into years.
-        if month > 12 {
-            year += month % 12;
-            month %= 12;
+        if seconds >= END_SECONDS as i128 {
+            panic!("Date must be < 10000");
         }
 
-        // Overflow days into months.
-        loop {
-            let mut corrected = false;
-            let days_in_month = super::statics::DIM[month - 1][super::statics::ly(year)];
-            if day > days_in_month {
-                corrected = true;
-                day -= days_in_month;
-                month += 1;
-            }
-            if month > 12 {
-                year += 1;
-                month = 1;
-            }
-            if !corrected {
-                break;
-            }
+        Self::from_epoch_parts(seconds as i64, nano)
+    }
+
+    /// Gets the specified date/time, rejecting any component which is out of
+    /// range rather than carrying it.
+    ///
+    /// A second of 60 is only in range during a leap second in the
+    /// [embedded table](crate::LeapSeconds::embedded), or in another with
+    /// [`Utc::from_ymdhms_strict_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ Component, Utc, };
+    ///
+    /// let dt = Utc::from_ymdhms_strict(2024, 2, 29, 23, 59, 59.5_f64).unwrap();
+    /// assert_eq!("2024-02-29T23:59:59.500000", dt.to_rfc3339_with_nano_buf(6));
+    ///
+    /// let err = Utc::from_ymdhms_strict(2023, 2, 29, 0, 0, 0_f64).unwrap_err();
+    /// assert_eq!(Component::Day, err.component());
+    ///
+    /// let err = Utc::from_ymdhms_strict(2024, 1, 1, 0, 0, 60_f64).unwrap_err();
+    /// assert_eq!(Component::Second, err.component());
+    ///
+    /// let dt = Utc::from_ymdhms_strict(2016, 12, 31, 23, 59, 60_f64).unwrap();
+    /// assert!(dt.is_leap_second());
+    /// ```
+    pub fn from_ymdhms_strict(
+        year: usize, month: usize, day: usize,
+        hour: usize, minute: usize, second: f64,
+    ) -> Result<Self, crate::RangeError> {
+        Self::from_ymdhms_strict_with(
+            year, month, day, hour, minute, second, &crate::LeapSeconds::embedded(),
+        )
+    }
+
+    /// Gets the specified date/time as [`Utc::from_ymdhms_strict`] does, but
+    /// with a second of 60 only in range during a leap second in the given
+    /// table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ Component, LeapSeconds, Utc, };
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let table = LeapSeconds::parse("2272060800 10").unwrap();
+    /// let err = Utc::from_ymdhms_strict_with(2016, 12, 31, 23, 59, 60_f64, &table).unwrap_err();
+    /// assert_eq!(Component::Second, err.component());
+    /// # }
+    /// ```
+    pub fn from_ymdhms_strict_with(
+        year: usize, month: usize, day: usize,
+        hour: usize, minute: usize, second: f64,
+        leap_seconds: &crate::LeapSeconds,
+    ) -> Result<Self, crate::RangeError> {
+        let leap_day = leap_seconds.has_leap_second(year, month, day);
+        match Self::out_of_range(year, month, day, hour, minute, second, leap_day) {
+            Some(component) => Err(crate::RangeError::new(component)),
+            None => Ok(Self::from_ymdhms_with(
+                year, month, day, hour, minute, second, leap_seconds,
+            )),
         }
+    }
 
-        let mut days = (super::statics::EPOCH_Y..year)
-            .map(|y| super::statics::DIY[super::statics::ly(y)])
-            .sum::<usize>();
-
-        for m in 1..(super::statics::MIY + 1) {
-            if m != month {
-                days += super::statics::DIM[m - 1][super::statics::ly(year)];
-            } else {
-                days += day - 1;
-                break;
-            }
+    /// Gets the specified date/time in a `const` context, failing to compile
+    /// if any component is out of range.
+    ///
+    /// # Panics
+    ///
+    /// Panics when any component is out of range, as with
+    /// [`Utc::from_ymdhms_strict`], so leap seconds are those of the
+    /// [embedded table](crate::LeapSeconds::embedded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// const CUTOVER: utc::Utc = utc::Utc::from_ymdhms_const(2024, 1, 9, 16, 21, 0.5_f64);
+    /// assert_eq!("2024-01-09T16:21:00.500000", CUTOVER.to_rfc3339_with_nano_buf(6));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const NOT_A_TIME: utc::Utc = utc::Utc::from_ymdhms_const(2024, 1, 9, 24, 0, 0_f64);
+    /// ```
+    pub const fn from_ymdhms_const(
+        year: usize, month: usize, day: usize,
+        hour: usize, minute: usize, second: f64,
+    ) -> Self {
+        let leap_day = super::leap::is_embedded_leap_day(year, month, day);
+        match Self::out_of_range(year, month, day, hour, minute, second, leap_day) {
+            Some(crate::Component::Year) => panic!("Year is out of range"),
+            Some(crate::Component::Month) => panic!("Month is out of range"),
+            Some(crate::Component::Day) => panic!("Day is out of range"),
+            Some(crate::Component::Hour) => panic!("Hour is out of range"),
+            Some(crate::Component::Minute) => panic!("Minute is out of range"),
+            Some(crate::Component::Second) => panic!("Second is out of range"),
+            None => Self::from_ymdhms(year, month, day, hour, minute, second),
         }
+    }
+
+    /// Gets the specified date at the start of the day in a `const` context,
+    /// failing to compile if the date does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// const LAUNCH: utc::Utc = utc::Utc::from_ymd_const(2024, 1, 1);
+    /// assert_eq!("2024-01-01T00:00:00", LAUNCH.to_rfc3339_buf());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const LEAP_DAY: utc::Utc = utc::Utc::from_ymd_const(2023, 2, 29);
+    /// ```
+    pub const fn from_ymd_const(year: usize, month: usize, day: usize) -> Self {
+        Self::from_ymdhms_const(year, month, day, 0, 0, 0_f64)
+    }
 
-        let hours = (days * 24) + hour;
-        let minutes = (hours * 60) + minute;
-        let seconds = (minutes * 60) + second;
-        let seconds_with_nanos = seconds as f64 + nano;
-        Self::from_seconds_since_epoch(seconds_with_nanos)
+    /// Gets the first component which is out of range, if any, with a
+    /// second of 60 in range at 23:59 if the day ends with a leap second.
+    const fn out_of_range(
+        year: usize, month: usize, day: usize,
+        hour: usize, minute: usize, second: f64,
+        leap_day: bool,
+    ) -> Option<crate::Component> {
+        if year < super::statics::MIN_Y || year > super::statics::MAX_Y {
+            Some(crate::Component::Year)
+        } else if month < 1 || month > super::statics::MIY {
+            Some(crate::Component::Month)
+        } else if !crate::calendar::is_valid_date(year, month, day) {
+            Some(crate::Component::Day)
+        } else if hour >= 24 {
+            Some(crate::Component::Hour)
+        } else if minute >= 60 {
+            Some(crate::Component::Minute)
+        } else if !(second >= 0_f64 && second < 60_f64) && !(
+            hour == 23 && minute == 59 && second >= 60_f64 && second < 61_f64 && leap_day
+        ) {
+            Some(crate::Component::Second)
+        } else {
+            None
+        }
     }
 
     /// Gets the specified date at the start of the day.
@@ -368,10 +876,56 @@ impl Utc {
     /// assert_eq!(0, dt.minute());
     /// assert_eq!(0_f64, dt.second_with_nano());
     /// ```
-    pub fn from_ymd(year: usize, month: usize, day: usize) -> Self {
+    pub const fn from_ymd(year: usize, month: usize, day: usize) -> Self {
         Self::from_ymdhms(year, month, day, 0, 0, 0_f64)
     }
 
+    /// Gets the date/time a number of seconds after the unix epoch, such as
+    /// a reading from a real-time clock, or `None` if it is in the year
+    /// 10000 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_epoch_seconds(1_704_817_260).unwrap();
+    /// assert_eq!(utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64), dt);
+    /// assert_eq!(None, utc::Utc::from_epoch_seconds(u64::MAX));
+    /// ```
+    pub const fn from_epoch_seconds(seconds: u64) -> Option<Self> {
+        Self::from_epoch_nanos(seconds as u128 * 1_000_000_000)
+    }
+
+    /// Gets the date/time a number of milliseconds after the unix epoch, or
+    /// `None` if it is in the year 10000 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_epoch_millis(1_704_817_260_500).unwrap();
+    /// assert_eq!(utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64), dt);
+    /// ```
+    pub const fn from_epoch_millis(millis: u64) -> Option<Self> {
+        Self::from_epoch_nanos(millis as u128 * 1_000_000)
+    }
+
+    /// Gets the date/time a number of nanoseconds after the unix epoch, or
+    /// `None` if it is in the year 10000 or later.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_epoch_nanos(1_704_817_260_250_000_000).unwrap();
+    /// assert_eq!(250_000_000, dt.nanosecond());
+    /// assert_eq!(None, utc::Utc::from_epoch_nanos(u128::MAX));
+    /// ```
+    pub const fn from_epoch_nanos(nanos: u128) -> Option<Self> {
+        if nanos >= END_SECONDS as u128 * 1_000_000_000 {
+            return None;
+        }
+
+        Self::from_nanos_since_epoch(nanos as i128)
+    }
+
     /// Gets the current date/time.
     ///
     /// # Examples
@@ -380,6 +934,7 @@ impl Utc {
     /// let now = utc::Utc::now();
     /// println!("{now}");
     /// ```
+    #[cfg(feature = "std")]
     pub fn now() -> Self {
         Self::from_seconds_since_epoch(std::time::SystemTime::now()
             .duration_since(std::time::SystemTime::UNIX_EPOCH)
@@ -394,16 +949,260 @@ impl Utc {
     ///
     /// ```
     /// let epoch = utc::Utc::epoch();
-    /// assert_eq!("1970-01-01T00:00:00.000000", epoch.as_rfc3339_nano());
+    /// assert_eq!("1970-01-01T00:00:00.000000", epoch.to_rfc3339_with_nano_buf(6));
+    /// ```
+    pub const fn epoch() -> Self {
+        Self::EPOCH
+    }
+
+    /// Describes this date/time relative to another using the default
+    /// [`RelativeFormat`](crate::RelativeFormat).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let now = utc::Utc::from_ymdhms(2024, 1, 9, 12, 0, 0_f64);
+    /// let then = utc::Utc::from_ymdhms(2024, 1, 8, 9, 0, 0_f64);
+    /// assert_eq!("yesterday", then.relative_to(&now));
+    /// assert_eq!("tomorrow", now.relative_to(&then));
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn relative_to(&self, other: &Utc) -> alloc::string::String {
+        crate::RelativeFormat::new().format(self, other)
+    }
+
+    /// Describes this date/time relative to the current date/time using the
+    /// default [`RelativeFormat`](crate::RelativeFormat).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert_eq!("just now", utc::Utc::now().humanize());
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn humanize(&self) -> alloc::string::String {
+        self.relative_to(&Self::now())
+    }
+
+    /// Parses a date/time in any of several common formats.
+    ///
+    /// The [`ParseHints`](crate::ParseHints) choose which
+    /// [`Format`](crate::Format)s are tried and in which order, how numeric
+    /// dates such as `03/04/2024` are read and the unit of epoch timestamps.
+    /// Numeric dates which could be read either way are reported as
+    /// [`ParseAnyError::Ambiguous`](crate::ParseAnyError::Ambiguous) unless
+    /// a [`DateOrder`](crate::DateOrder) is hinted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ DateOrder, ParseAnyError, ParseHints, Utc, };
+    ///
+    /// let hints = ParseHints::new();
+    /// let expected = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64);
+    /// assert_eq!(expected, Utc::parse_any("2024-01-09T16:21:00Z", &hints).unwrap());
+    /// assert_eq!(expected, Utc::parse_any("2024-01-09T18:21:00+02:00", &hints).unwrap());
+    /// assert_eq!(expected, Utc::parse_any("Tue, 09 Jan 2024 16:21:00 GMT", &hints).unwrap());
+    /// assert_eq!(expected, Utc::parse_any("1704817260", &hints).unwrap());
+    /// assert_eq!(expected, Utc::parse_any("1704817260000", &hints).unwrap());
+    ///
+    /// assert_eq!(Utc::from_ymd(2024, 1, 9), Utc::parse_any("9 Jan 2024", &hints).unwrap());
+    /// assert_eq!(Utc::from_ymd(2024, 9, 13), Utc::parse_any("09/13/2024", &hints).unwrap());
+    /// assert_eq!(
+    ///     Err(ParseAnyError::Ambiguous {
+    ///         day_first: Box::new(Utc::from_ymd(2024, 4, 3)),
+    ///         month_first: Box::new(Utc::from_ymd(2024, 3, 4)),
+    ///     }),
+    ///     Utc::parse_any("03/04/2024", &hints)
+    /// );
+    ///
+    /// let hints = hints.date_order(DateOrder::DayFirst);
+    /// assert_eq!(Utc::from_ymd(2024, 4, 3), Utc::parse_any("03/04/2024", &hints).unwrap());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_any(
+        input: &str, hints: &crate::ParseHints,
+    ) -> Result<Self, crate::ParseAnyError> {
+        crate::parse::parse_any(input, hints)
+    }
+
+    /// Gets the number of whole days since the unix epoch.
+    pub(crate) const fn days_since_epoch(&self) -> i64 {
+        super::statics::days_from_civil(self.year as i64, self.month, self.day)
+    }
+
+    /// Gets the number of nanoseconds since the unix epoch.
+    pub(crate) const fn nanos_since_epoch(&self) -> i128 {
+        let seconds = self.days_since_epoch() * 86_400 +
+            (self.hour * 3_600 + self.minute * 60 + self.second) as i64;
+
+        seconds as i128 * 1_000_000_000 +
+            super::statics::round(self.nano * 1_000_000_000_f64) as i128
+    }
+
+    /// Gets the number of whole milliseconds since the unix epoch, or zero
+    /// if it is before the epoch.
+    pub(crate) const fn millis_since_epoch(&self) -> u64 {
+        let nanos = self.nanos_since_epoch();
+        if nanos < 0 { 0 } else { (nanos / 1_000_000) as u64 }
+    }
+
+    /// Whether or not the date/time is during a leap second, 23:59:60.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(utc::Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64).is_leap_second());
+    /// assert!(!utc::Utc::from_ymdhms(2016, 12, 31, 23, 59, 59_f64).is_leap_second());
+    /// ```
+    pub const fn is_leap_second(&self) -> bool {
+        self.second == 60
+    }
+
+    /// Gets the number of leap seconds from one date/time up to another,
+    /// negative if `b` is before `a`, using the
+    /// [embedded table](crate::LeapSeconds::embedded).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::Utc;
+    ///
+    /// let a = Utc::from_ymd(1972, 1, 1);
+    /// let b = Utc::from_ymd(2024, 1, 1);
+    /// assert_eq!(27, Utc::leap_seconds_between(&a, &b));
+    ///
+    /// let leap = Utc::from_ymdhms(2016, 12, 31, 23, 59, 60_f64);
+    /// assert_eq!(26, Utc::leap_seconds_between(&a, &leap));
+    /// assert_eq!(1, Utc::leap_seconds_between(&leap, &Utc::from_ymd(2017, 1, 1)));
+    /// ```
+    pub fn leap_seconds_between(a: &Utc, b: &Utc) -> i64 {
+        Self::leap_seconds_between_with(a, b, &crate::LeapSeconds::embedded())
+    }
+
+    /// Gets the number of leap seconds from one date/time up to another,
+    /// negative if `b` is before `a`, using the given table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ LeapSeconds, Utc, };
+    ///
+    /// # #[cfg(feature = "alloc")] {
+    /// let table = LeapSeconds::parse("3692217600 37\n4307472000 38").unwrap();
+    /// let a = Utc::from_ymd(2024, 1, 1);
+    /// let b = Utc::from_ymd(2037, 1, 1);
+    /// assert_eq!(0, Utc::leap_seconds_between(&a, &b));
+    /// assert_eq!(1, Utc::leap_seconds_between_with(&a, &b, &table));
+    /// # }
+    /// ```
+    pub fn leap_seconds_between_with(a: &Utc, b: &Utc, leap_seconds: &crate::LeapSeconds) -> i64 {
+        leap_seconds.leap_seconds_between(a, b)
+    }
+
+    /// Gets the amount of time elapsed from an earlier date/time to this one,
+    /// or `None` if `earlier` is later than this date/time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a = utc::Utc::from_ymdhms(2024, 1, 1, 0, 0, 0_f64);
+    /// let b = utc::Utc::from_ymdhms(2024, 1, 1, 1, 30, 5_f64);
+    /// assert_eq!(Some(std::time::Duration::from_secs(5405)), b.duration_since(&a));
+    /// assert_eq!(None, a.duration_since(&b));
+    /// ```
+    pub fn duration_since(&self, earlier: &Utc) -> Option<core::time::Duration> {
+        let nanos = self.nanos_since_epoch() - earlier.nanos_since_epoch();
+        if nanos < 0 {
+            return None;
+        }
+
+        Some(core::time::Duration::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        ))
+    }
+
+    /// Adds a duration, returning `None` if the result cannot be represented.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 23, 0, 0_f64);
+    /// let later = dt.checked_add(std::time::Duration::from_secs(7_200)).unwrap();
+    /// assert_eq!("2024-01-10T01:00:00", later.to_rfc3339_buf());
+    /// ```
+    pub fn checked_add(&self, duration: core::time::Duration) -> Option<Self> {
+        Self::from_nanos_since_epoch(
+            self.nanos_since_epoch().checked_add(duration.as_nanos() as i128)?
+        )
+    }
+
+    /// Subtracts a duration, returning `None` if the result would be before
+    /// [`Utc::MIN`].
+    ///
+    /// # Examples
+    ///
     /// ```
-    pub fn epoch() -> Self {
-        Self::from_seconds_since_epoch(0_f64)
+    /// let dt = utc::Utc::from_ymd(2024, 1, 1);
+    /// let earlier = dt.checked_sub(std::time::Duration::from_secs(1)).unwrap();
+    /// assert_eq!("2023-12-31T23:59:59", earlier.to_rfc3339_buf());
+    /// assert_eq!(None, utc::Utc::MIN.checked_sub(std::time::Duration::from_secs(1)));
+    /// ```
+    pub fn checked_sub(&self, duration: core::time::Duration) -> Option<Self> {
+        Self::from_nanos_since_epoch(
+            self.nanos_since_epoch().checked_sub(duration.as_nanos() as i128)?
+        )
+    }
+
+    /// Gets the amount of time between two date/times regardless of which
+    /// is earlier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let a = utc::Utc::from_ymd(2024, 1, 1);
+    /// let b = utc::Utc::from_ymd(2024, 1, 2);
+    /// assert_eq!(std::time::Duration::from_secs(86_400), a.abs_diff(&b));
+    /// assert_eq!(a.abs_diff(&b), b.abs_diff(&a));
+    /// ```
+    pub fn abs_diff(&self, other: &Utc) -> core::time::Duration {
+        self.duration_since(other)
+            .or_else(|| other.duration_since(self))
+            .unwrap()
     }
 }
 
-impl std::fmt::Display for Utc {
-    fn fmt(&self, fmtr: &mut std::fmt::Formatter) -> std::fmt::Result {
-        fmtr.write_str(&self.as_rfc3339_nano())
+/// Formats like [`Utc::as_rfc3339_nano`], or like [`Utc::as_iso8601`] with
+/// the alternate flag.
+///
+/// The precision sets the number of fractional digits, which are zero
+/// beyond nanoseconds, and width, fill and alignment pad the whole
+/// date/time.
+///
+/// # Examples
+///
+/// ```
+/// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 12, 5, 57.25_f64);
+/// assert_eq!("2024-01-09T12:05:57.250000", format!("{dt}"));
+/// assert_eq!("2024-01-09T12:05:57.2", format!("{dt:.1}"));
+/// assert_eq!("2024-01-09T12:05:57", format!("{dt:.0}"));
+/// assert_eq!("2024-01-09 12:05:57.250", format!("{dt:#}"));
+/// assert_eq!("2024-01-09 12:05:57.250000000", format!("{dt:#.9}"));
+/// assert_eq!("2024-01-09T12:05:57.250000000000", format!("{dt:.12}"));
+/// assert_eq!("[   2024-01-09T12:05:57.25]", format!("[{dt:>25.2}]"));
+/// ```
+impl core::fmt::Display for Utc {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let (buf, precision) = if fmtr.alternate() {
+            (self.to_iso8601_with_precision_buf(0), fmtr.precision().unwrap_or(3))
+        } else {
+            (self.to_rfc3339_buf(), fmtr.precision().unwrap_or(6))
+        };
+        let fraction = crate::format::Fraction::new(self, precision);
+        crate::format::pad(fmtr, buf.len() + fraction.len(), |fmtr| {
+            write!(fmtr, "{buf}{fraction}")
+        })
     }
 }
 
@@ -416,6 +1215,7 @@ impl PartialEq<Utc> for Utc {
             self.day_of_week.eq(other.day_of_week) &&
             self.hour.eq(&other.hour) &&
             self.minute.eq(&other.minute) &&
+            self.second.eq(&other.second) &&
             self.nano.eq(&other.nano)
     }
 }
@@ -423,14 +1223,14 @@ impl PartialEq<Utc> for Utc {
 impl Eq for Utc {}
 
 impl PartialOrd for Utc {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
         match self.year.cmp(&other.year) {
-            std::cmp::Ordering::Equal => match self.month.cmp(&other.month) {
-                std::cmp::Ordering::Equal => match self.day.cmp(&other.day) {
-                    std::cmp::Ordering::Equal => match self.hour.cmp(&other.hour) {
-                        std::cmp::Ordering::Equal => match self.minute.cmp(&other.minute) {
-                            std::cmp::Ordering::Equal => match self.second.cmp(&other.second) {
-                                std::cmp::Ordering::Equal => self.nano.partial_cmp(&other.nano),
+            core::cmp::Ordering::Equal => match self.month.cmp(&other.month) {
+                core::cmp::Ordering::Equal => match self.day.cmp(&other.day) {
+                    core::cmp::Ordering::Equal => match self.hour.cmp(&other.hour) {
+                        core::cmp::Ordering::Equal => match self.minute.cmp(&other.minute) {
+                            core::cmp::Ordering::Equal => match self.second.cmp(&other.second) {
+                                core::cmp::Ordering::Equal => self.nano.partial_cmp(&other.nano),
                                 o => Some(o),
                             },
                             o => Some(o),
@@ -446,3 +1246,76 @@ impl PartialOrd for Utc {
     }
 }
 
+impl core::ops::Add<core::time::Duration> for Utc {
+    type Output = Utc;
+
+    fn add(self, rhs: core::time::Duration) -> Self::Output {
+        self.checked_add(rhs).expect("overflow when adding duration to date/time")
+    }
+}
+
+impl core::ops::Sub<core::time::Duration> for Utc {
+    type Output = Utc;
+
+    fn sub(self, rhs: core::time::Duration) -> Self::Output {
+        self.checked_sub(rhs).expect("Date must be >= 0001")
+    }
+}
+
+/// Converts a system time, failing if it is before 0001 or after 9999.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{ Duration, SystemTime, };
+///
+/// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_704_817_260);
+/// let dt = utc::Utc::try_from(time).unwrap();
+/// assert_eq!(utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64), dt);
+///
+/// let time = SystemTime::UNIX_EPOCH - Duration::from_millis(500);
+/// let dt = utc::Utc::try_from(time).unwrap();
+/// assert_eq!(utc::Utc::from_ymdhms(1969, 12, 31, 23, 59, 59.5_f64), dt);
+/// ```
+#[cfg(feature = "std")]
+impl TryFrom<std::time::SystemTime> for Utc {
+    type Error = crate::RangeError;
+
+    fn try_from(time: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let nanos = match time.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(since) => i128::try_from(since.as_nanos()).ok(),
+            Err(before) => i128::try_from(before.duration().as_nanos()).ok().map(|n| -n),
+        };
+        nanos.and_then(Self::from_nanos_since_epoch)
+            .ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+/// Converts to a system time.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{ Duration, SystemTime, };
+///
+/// let dt = utc::Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// assert_eq!(
+///     SystemTime::UNIX_EPOCH + Duration::from_millis(1_704_817_260_500),
+///     SystemTime::from(dt)
+/// );
+/// ```
+#[cfg(feature = "std")]
+impl From<Utc> for std::time::SystemTime {
+    fn from(dt: Utc) -> Self {
+        let nanos = dt.nanos_since_epoch();
+        let duration = core::time::Duration::new(
+            (nanos.unsigned_abs() / 1_000_000_000) as u64,
+            (nanos.unsigned_abs() % 1_000_000_000) as u32,
+        );
+        if nanos < 0 {
+            std::time::SystemTime::UNIX_EPOCH - duration
+        } else {
+            std::time::SystemTime::UNIX_EPOCH + duration
+        }
+    }
+}
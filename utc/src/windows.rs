@@ -0,0 +1,429 @@
+/// The number of 100 nanosecond ticks in a second.
+const TICKS_PER_SECOND: i128 = 10_000_000;
+
+/// Seconds from 1601-01-01, the FILETIME epoch, to the unix epoch.
+const FILETIME_UNIX_OFFSET: i128 = 11_644_473_600;
+
+/// Seconds from 0001-01-01, the .NET epoch, to the unix epoch.
+const DOTNET_UNIX_OFFSET: i128 = 62_135_596_800;
+
+/// The ticks of .NET's `DateTime.MaxValue`, 9999-12-31T23:59:59.9999999.
+const DOTNET_MAX_TICKS: u64 = 3_155_378_975_999_999_999;
+
+/// The bits of a .NET binary `DateTime` holding the ticks.
+const DOTNET_TICKS_MASK: u64 = (1 << 62) - 1;
+
+/// Gets the number of ticks from the unix epoch to a date/time, rounded
+/// down.
+const fn ticks_since_epoch(dt: &crate::Utc) -> i128 {
+    dt.nanos_since_epoch().div_euclid(100)
+}
+
+/// Gets the date/time a number of ticks from the unix epoch, or `None` if it
+/// is out of range.
+const fn from_ticks_since_epoch(ticks: i128) -> Option<crate::Utc> {
+    crate::Utc::from_nanos_since_epoch(ticks * 100)
+}
+
+/// A Windows `FILETIME`: the number of 100 nanosecond ticks since
+/// 1601-01-01T00:00:00 UTC, as used by NTFS, the registry and event logs.
+///
+/// Conversions from [`crate::Utc`] round down to the tick. A leap second,
+/// 23:59:60, gets the ticks of the midnight after it.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ FileTime, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let filetime = FileTime::from_utc(&dt).unwrap();
+/// assert_eq!(133_492_908_605_000_000, filetime.ticks());
+/// assert_eq!(Some(dt), filetime.to_utc());
+/// assert_eq!(filetime, FileTime::from_parts(filetime.low(), filetime.high()));
+/// assert_eq!(None, FileTime::from_utc(&Utc::from_ymd(1600, 12, 31)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileTime {
+    ticks: u64,
+}
+
+impl FileTime {
+    /// Gets a `FILETIME` from its number of ticks.
+    pub const fn new(ticks: u64) -> Self {
+        Self { ticks, }
+    }
+
+    /// Gets a `FILETIME` from its `dwLowDateTime` and `dwHighDateTime`.
+    pub const fn from_parts(low: u32, high: u32) -> Self {
+        Self { ticks: (high as u64) << 32 | low as u64, }
+    }
+
+    /// Gets the number of ticks since 1601-01-01T00:00:00.
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Gets the `dwLowDateTime`, the lower 32 bits of the ticks.
+    pub const fn low(&self) -> u32 {
+        self.ticks as u32
+    }
+
+    /// Gets the `dwHighDateTime`, the upper 32 bits of the ticks.
+    pub const fn high(&self) -> u32 {
+        (self.ticks >> 32) as u32
+    }
+
+    /// Gets the `FILETIME` of a date/time, or `None` if it is before 1601.
+    pub const fn from_utc(dt: &crate::Utc) -> Option<Self> {
+        let ticks = ticks_since_epoch(dt) + FILETIME_UNIX_OFFSET * TICKS_PER_SECOND;
+        if ticks < 0 {
+            return None;
+        }
+
+        Some(Self { ticks: ticks as u64, })
+    }
+
+    /// Gets the date/time, or `None` if it is after 9999.
+    pub const fn to_utc(&self) -> Option<crate::Utc> {
+        from_ticks_since_epoch(self.ticks as i128 - FILETIME_UNIX_OFFSET * TICKS_PER_SECOND)
+    }
+
+    /// Encodes the `FILETIME` as it is laid out in memory and on disk,
+    /// little-endian.
+    pub const fn to_bytes(&self) -> [u8; 8] {
+        self.ticks.to_le_bytes()
+    }
+
+    /// Decodes a `FILETIME` as it is laid out in memory and on disk,
+    /// little-endian.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ FileTime, Utc, };
+    ///
+    /// // As in a registry export, hex(b):00,80,3e,d5,de,b1,9d,01
+    /// let filetime = FileTime::from_bytes([0x00, 0x80, 0x3e, 0xd5, 0xde, 0xb1, 0x9d, 0x01]);
+    /// assert_eq!(Some(Utc::from_ymd(1970, 1, 1)), filetime.to_utc());
+    /// ```
+    pub const fn from_bytes(bytes: [u8; 8]) -> Self {
+        Self { ticks: u64::from_le_bytes(bytes), }
+    }
+}
+
+impl TryFrom<FileTime> for crate::Utc {
+    type Error = crate::RangeError;
+
+    fn try_from(filetime: FileTime) -> Result<Self, Self::Error> {
+        filetime.to_utc().ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+impl TryFrom<crate::Utc> for FileTime {
+    type Error = crate::RangeError;
+
+    fn try_from(dt: crate::Utc) -> Result<Self, Self::Error> {
+        Self::from_utc(&dt).ok_or(crate::RangeError::new(crate::Component::Year))
+    }
+}
+
+/// The `Kind` of a .NET `DateTime`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DateTimeKind {
+    /// Neither UTC nor local time, taken as UTC here.
+    Unspecified,
+    /// UTC.
+    Utc,
+    /// The local time of the machine which wrote it.
+    Local,
+}
+
+/// A .NET `DateTime`: the number of 100 nanosecond ticks since
+/// 0001-01-01T00:00:00 and a [`DateTimeKind`].
+///
+/// Every `DateTime` is within [`Utc::MIN`] and [`Utc::MAX`], so conversions
+/// to [`crate::Utc`] are lossless, and conversions from it round down to the
+/// tick.
+///
+/// In the binary form from `DateTime.ToBinary`, the kind is in the top two
+/// bits and local times are stored as the UTC ticks, so they convert to the
+/// same instant whatever the time zone of the machine which wrote them.
+///
+/// [`Utc::MIN`]: crate::Utc::MIN
+/// [`Utc::MAX`]: crate::Utc::MAX
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ DateTimeKind, DotNetDateTime, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let date_time = DotNetDateTime::from_utc(&dt);
+/// assert_eq!(638_404_140_605_000_000, date_time.ticks());
+/// assert_eq!(DateTimeKind::Utc, date_time.kind());
+/// assert_eq!(dt, date_time.to_utc());
+///
+/// let binary = date_time.to_binary();
+/// assert_eq!(0x48dc_112e_f7e6_4940_u64 as i64, binary);
+/// assert_eq!(Some(date_time), DotNetDateTime::from_binary(binary));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DotNetDateTime {
+    ticks: u64,
+    kind: DateTimeKind,
+}
+
+impl DotNetDateTime {
+    /// Gets a `DateTime` from its ticks, which for local times are the UTC
+    /// ticks, and kind, or `None` if the ticks are after 9999.
+    pub const fn new(ticks: u64, kind: DateTimeKind) -> Option<Self> {
+        if ticks > DOTNET_MAX_TICKS {
+            return None;
+        }
+
+        Some(Self { ticks, kind, })
+    }
+
+    /// Gets the UTC `DateTime` of a date/time.
+    pub const fn from_utc(dt: &crate::Utc) -> Self {
+        let ticks = ticks_since_epoch(dt) + DOTNET_UNIX_OFFSET * TICKS_PER_SECOND;
+        Self { ticks: ticks as u64, kind: DateTimeKind::Utc, }
+    }
+
+    /// Gets the date/time.
+    pub const fn to_utc(&self) -> crate::Utc {
+        match from_ticks_since_epoch(self.ticks as i128 - DOTNET_UNIX_OFFSET * TICKS_PER_SECOND) {
+            Some(dt) => dt,
+            None => panic!("Ticks must be <= DateTime.MaxValue"),
+        }
+    }
+
+    /// Gets the number of ticks since 0001-01-01T00:00:00, in UTC for local
+    /// times.
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Gets the kind.
+    pub const fn kind(&self) -> DateTimeKind {
+        self.kind
+    }
+
+    /// Encodes the `DateTime` as `DateTime.ToBinary` does.
+    pub const fn to_binary(&self) -> i64 {
+        let kind: u64 = match self.kind {
+            DateTimeKind::Unspecified => 0,
+            DateTimeKind::Utc => 1,
+            DateTimeKind::Local => 2,
+        };
+        (kind << 62 | self.ticks) as i64
+    }
+
+    /// Decodes a `DateTime` as `DateTime.FromBinary` does, without moving
+    /// local times into this machine's time zone, or `None` if it is out of
+    /// range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ DateTimeKind, DotNetDateTime, Utc, };
+    ///
+    /// let local = DotNetDateTime::from_binary(0x88dc_112e_f7e6_4940_u64 as i64).unwrap();
+    /// assert_eq!(DateTimeKind::Local, local.kind());
+    /// assert_eq!(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64), local.to_utc());
+    /// ```
+    pub const fn from_binary(binary: i64) -> Option<Self> {
+        let binary = binary as u64;
+        let ticks = binary & DOTNET_TICKS_MASK;
+        let kind = match binary >> 62 {
+            0 => DateTimeKind::Unspecified,
+            1 => DateTimeKind::Utc,
+            // Either way round a daylight saving time change.
+            _ => {
+                // Local times just after 0001-01-01 in UTC but before it
+                // locally wrap around to the end of the ticks.
+                if ticks > DOTNET_TICKS_MASK + 1 - 86_400 * TICKS_PER_SECOND as u64 {
+                    return None;
+                }
+                DateTimeKind::Local
+            },
+        };
+        Self::new(ticks, kind)
+    }
+}
+
+impl From<crate::Utc> for DotNetDateTime {
+    fn from(dt: crate::Utc) -> Self {
+        Self::from_utc(&dt)
+    }
+}
+
+impl From<DotNetDateTime> for crate::Utc {
+    fn from(date_time: DotNetDateTime) -> Self {
+        date_time.to_utc()
+    }
+}
+
+/// A Windows `SYSTEMTIME`: a date/time in separate fields, each a `WORD`.
+///
+/// The day of the week is filled in from the date and ignored when reading
+/// one, as `SystemTimeToFileTime` does.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ Utc, Win32SystemTime, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let system_time = Win32SystemTime::from_utc(&dt).unwrap();
+/// assert_eq!(2, system_time.day_of_week());
+/// assert_eq!(500, system_time.milliseconds());
+/// assert_eq!(Ok(dt), system_time.to_utc());
+/// assert_eq!(system_time, Win32SystemTime::from_bytes(system_time.to_bytes()));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Win32SystemTime {
+    year: u16,
+    month: u16,
+    day_of_week: u16,
+    day: u16,
+    hour: u16,
+    minute: u16,
+    second: u16,
+    milliseconds: u16,
+}
+
+impl Win32SystemTime {
+    /// Gets the `SYSTEMTIME` of a date/time, truncated to the millisecond,
+    /// or `None` if it is before 1601.
+    pub fn from_utc(dt: &crate::Utc) -> Option<Self> {
+        if dt.year() < 1601 {
+            return None;
+        }
+
+        // Sunday is 0.
+        let day_of_week = (dt.weekday().days_from_monday() + 1) % 7;
+        Some(Self {
+            year: dt.year() as u16,
+            month: dt.month() as u16,
+            day_of_week: day_of_week as u16,
+            day: dt.day() as u16,
+            hour: dt.hour() as u16,
+            minute: dt.minute() as u16,
+            second: dt.second() as u16,
+            milliseconds: (dt.nanosecond() / 1_000_000) as u16,
+        })
+    }
+
+    /// Gets the date/time, or the first field which is out of range.
+    ///
+    /// A second of 60 is only in range during a leap second, as Windows
+    /// gives when leap second support is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ Component, Win32SystemTime, };
+    ///
+    /// let mut bytes = [0; 16];
+    /// bytes[0..2].copy_from_slice(&2023_u16.to_le_bytes());
+    /// bytes[2..4].copy_from_slice(&2_u16.to_le_bytes());
+    /// bytes[6..8].copy_from_slice(&29_u16.to_le_bytes());
+    /// let system_time = Win32SystemTime::from_bytes(bytes);
+    /// assert_eq!(Component::Day, system_time.to_utc().unwrap_err().component());
+    /// ```
+    pub fn to_utc(&self) -> Result<crate::Utc, crate::RangeError> {
+        if self.year < 1601 {
+            return Err(crate::RangeError::new(crate::Component::Year));
+        }
+        if self.milliseconds >= 1_000 {
+            return Err(crate::RangeError::new(crate::Component::Second));
+        }
+
+        crate::Utc::from_ymdhms_strict(
+            self.year as usize, self.month as usize, self.day as usize,
+            self.hour as usize, self.minute as usize,
+            self.second as f64 + self.milliseconds as f64 / 1_000_f64,
+        )
+    }
+
+    /// Gets the `wYear`.
+    pub const fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Gets the `wMonth`, from 1 for January.
+    pub const fn month(&self) -> u16 {
+        self.month
+    }
+
+    /// Gets the `wDayOfWeek`, from 0 for Sunday.
+    pub const fn day_of_week(&self) -> u16 {
+        self.day_of_week
+    }
+
+    /// Gets the `wDay`.
+    pub const fn day(&self) -> u16 {
+        self.day
+    }
+
+    /// Gets the `wHour`.
+    pub const fn hour(&self) -> u16 {
+        self.hour
+    }
+
+    /// Gets the `wMinute`.
+    pub const fn minute(&self) -> u16 {
+        self.minute
+    }
+
+    /// Gets the `wSecond`.
+    pub const fn second(&self) -> u16 {
+        self.second
+    }
+
+    /// Gets the `wMilliseconds`.
+    pub const fn milliseconds(&self) -> u16 {
+        self.milliseconds
+    }
+
+    /// Encodes the `SYSTEMTIME` as it is laid out in memory, each field
+    /// little-endian in declaration order.
+    pub const fn to_bytes(&self) -> [u8; 16] {
+        let fields = [
+            self.year, self.month, self.day_of_week, self.day,
+            self.hour, self.minute, self.second, self.milliseconds,
+        ];
+        let mut bytes = [0; 16];
+        let mut i = 0;
+        while i < fields.len() {
+            let field = fields[i].to_le_bytes();
+            bytes[i * 2] = field[0];
+            bytes[i * 2 + 1] = field[1];
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Decodes a `SYSTEMTIME` as it is laid out in memory, without checking
+    /// the fields.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        let mut fields = [0; 8];
+        let mut i = 0;
+        while i < fields.len() {
+            fields[i] = u16::from_le_bytes([bytes[i * 2], bytes[i * 2 + 1]]);
+            i += 1;
+        }
+        Self {
+            year: fields[0],
+            month: fields[1],
+            day_of_week: fields[2],
+            day: fields[3],
+            hour: fields[4],
+            minute: fields[5],
+            second: fields[6],
+            milliseconds: fields[7],
+        }
+    }
+}
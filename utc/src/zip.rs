@@ -0,0 +1,308 @@
+/// The flag of an extended timestamp's modification time.
+const MODIFIED: u8 = 0x01;
+
+/// The flag of an extended timestamp's access time.
+const ACCESSED: u8 = 0x02;
+
+/// The flag of an extended timestamp's creation time.
+const CREATED: u8 = 0x04;
+
+/// Gets the seconds since the unix epoch of a date/time, rounded down, or
+/// an error if they do not fit in 32 bits.
+fn seconds_since_epoch(dt: &crate::Utc) -> Result<i32, crate::RangeError> {
+    i32::try_from(dt.nanos_since_epoch().div_euclid(1_000_000_000))
+        .map_err(|_| crate::RangeError::new(crate::Component::Year))
+}
+
+/// Gets the date/time a number of seconds from the unix epoch.
+fn from_seconds_since_epoch(seconds: i32) -> Option<crate::Utc> {
+    crate::Utc::from_nanos_since_epoch(seconds as i128 * 1_000_000_000)
+}
+
+/// A ZIP extended timestamp extra field, 0x5455 or "UT", as written by
+/// Info-ZIP: up to three times as signed 32-bit seconds since the unix epoch,
+/// so from 1901-12-13T20:45:52 to 2038-01-19T03:14:07.
+///
+/// Times are rounded down to the second. A leap second, 23:59:60, gets the
+/// seconds of the midnight after it.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ ExtendedTimestamp, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let timestamp = ExtendedTimestamp::new().with_modified(&dt).unwrap();
+/// assert_eq!(Some(Utc::from_ymdhms(2024, 1, 9, 16, 21, 0_f64)), timestamp.modified());
+/// assert_eq!(None, timestamp.accessed());
+///
+/// let data = [0x01, 0x6c, 0x72, 0x9d, 0x65];
+/// assert_eq!(Some(timestamp), ExtendedTimestamp::from_data(&data));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ExtendedTimestamp {
+    modified: Option<i32>,
+    accessed: Option<i32>,
+    created: Option<i32>,
+}
+
+impl ExtendedTimestamp {
+    /// The ID in the header of the extra field.
+    pub const HEADER_ID: u16 = 0x5455;
+
+    /// Gets an extended timestamp without any times.
+    pub const fn new() -> Self {
+        Self { modified: None, accessed: None, created: None, }
+    }
+
+    /// Sets the modification time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the [`Component::Year`] if the time is before
+    /// 1901-12-13T20:45:52 or after 2038-01-19T03:14:07.
+    ///
+    /// [`Component::Year`]: crate::Component::Year
+    pub fn with_modified(mut self, dt: &crate::Utc) -> Result<Self, crate::RangeError> {
+        self.modified = Some(seconds_since_epoch(dt)?);
+        Ok(self)
+    }
+
+    /// Sets the access time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the [`Component::Year`] if the time is before
+    /// 1901-12-13T20:45:52 or after 2038-01-19T03:14:07.
+    ///
+    /// [`Component::Year`]: crate::Component::Year
+    pub fn with_accessed(mut self, dt: &crate::Utc) -> Result<Self, crate::RangeError> {
+        self.accessed = Some(seconds_since_epoch(dt)?);
+        Ok(self)
+    }
+
+    /// Sets the creation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for the [`Component::Year`] if the time is before
+    /// 1901-12-13T20:45:52 or after 2038-01-19T03:14:07.
+    ///
+    /// [`Component::Year`]: crate::Component::Year
+    pub fn with_created(mut self, dt: &crate::Utc) -> Result<Self, crate::RangeError> {
+        self.created = Some(seconds_since_epoch(dt)?);
+        Ok(self)
+    }
+
+    /// Gets the modification time, if present.
+    pub fn modified(&self) -> Option<crate::Utc> {
+        self.modified.and_then(from_seconds_since_epoch)
+    }
+
+    /// Gets the access time, if present.
+    pub fn accessed(&self) -> Option<crate::Utc> {
+        self.accessed.and_then(from_seconds_since_epoch)
+    }
+
+    /// Gets the creation time, if present.
+    pub fn created(&self) -> Option<crate::Utc> {
+        self.created.and_then(from_seconds_since_epoch)
+    }
+
+    /// Decodes the data of the extra field, after its header, from either
+    /// a local header or the central directory, or `None` if it is empty.
+    ///
+    /// The central directory only has the modification time, though its
+    /// flags are those of the local header, so any time flagged but missing
+    /// is taken as absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ ExtendedTimestamp, Utc, };
+    ///
+    /// // Modification and access times flagged, only the first present.
+    /// let timestamp = ExtendedTimestamp::from_data(&[0x03, 0x00, 0x00, 0x00, 0x00]).unwrap();
+    /// assert_eq!(Some(Utc::epoch()), timestamp.modified());
+    /// assert_eq!(None, timestamp.accessed());
+    /// ```
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        let (&flags, mut times) = data.split_first()?;
+        let mut timestamp = Self::new();
+        for (flag, time) in [
+            (MODIFIED, &mut timestamp.modified),
+            (ACCESSED, &mut timestamp.accessed),
+            (CREATED, &mut timestamp.created),
+        ] {
+            if flags & flag == 0 || times.len() < 4 {
+                continue;
+            }
+
+            *time = Some(i32::from_le_bytes([times[0], times[1], times[2], times[3]]));
+            times = &times[4..];
+        }
+        Some(timestamp)
+    }
+
+    /// Gets the flags of the times which are present.
+    #[cfg(feature = "alloc")]
+    fn flags(&self) -> u8 {
+        [(MODIFIED, self.modified), (ACCESSED, self.accessed), (CREATED, self.created)]
+            .into_iter()
+            .filter(|(_, time)| time.is_some())
+            .fold(0, |flags, (flag, _)| flags | flag)
+    }
+
+    /// Encodes the extra field, with its header, with the times present,
+    /// for a local header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ ExtendedTimestamp, Utc, };
+    ///
+    /// let timestamp = ExtendedTimestamp::new()
+    ///     .with_modified(&Utc::epoch()).unwrap()
+    ///     .with_created(&Utc::from_epoch_seconds(1).unwrap()).unwrap();
+    /// assert_eq!(
+    ///     vec![0x55, 0x54, 0x09, 0x00, 0x05, 0, 0, 0, 0, 1, 0, 0, 0],
+    ///     timestamp.to_local_field()
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_local_field(&self) -> alloc::vec::Vec<u8> {
+        let times = [self.modified, self.accessed, self.created];
+        self.field_with(&times)
+    }
+
+    /// Encodes the extra field, with its header, for the central directory,
+    /// which only has the modification time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use utc::{ ExtendedTimestamp, Utc, };
+    ///
+    /// let timestamp = ExtendedTimestamp::new()
+    ///     .with_modified(&Utc::epoch()).unwrap()
+    ///     .with_created(&Utc::from_epoch_seconds(1).unwrap()).unwrap();
+    /// assert_eq!(vec![0x55, 0x54, 0x05, 0x00, 0x05, 0, 0, 0, 0], timestamp.to_central_field());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_central_field(&self) -> alloc::vec::Vec<u8> {
+        self.field_with(&[self.modified])
+    }
+
+    /// Encodes the extra field with the times.
+    #[cfg(feature = "alloc")]
+    fn field_with(&self, times: &[Option<i32>]) -> alloc::vec::Vec<u8> {
+        let mut field = alloc::vec::Vec::with_capacity(17);
+        field.extend_from_slice(&Self::HEADER_ID.to_le_bytes());
+        field.extend_from_slice(&[0, 0]);
+        field.push(self.flags());
+        for time in times.iter().flatten() {
+            field.extend_from_slice(&time.to_le_bytes());
+        }
+        let len = (field.len() - 4) as u16;
+        field[2..4].copy_from_slice(&len.to_le_bytes());
+        field
+    }
+}
+
+/// A ZIP NTFS extra field, 0x000a, as written by Windows tools: the
+/// modification, access and creation times as [`crate::FileTime`]s, so
+/// with 100 nanosecond resolution.
+///
+/// # Examples
+///
+/// ```
+/// use utc::{ FileTime, NtfsTimes, Utc, };
+///
+/// let dt = Utc::from_ymdhms(2024, 1, 9, 16, 21, 0.5_f64);
+/// let filetime = FileTime::from_utc(&dt).unwrap();
+/// let times = NtfsTimes::new(filetime, filetime, filetime);
+///
+/// let field = times.to_field();
+/// assert_eq!([0x0a, 0x00, 0x20, 0x00], field[..4]);
+/// let times = NtfsTimes::from_data(&field[4..]).unwrap();
+/// assert_eq!(Some(dt), times.modified().to_utc());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NtfsTimes {
+    modified: crate::FileTime,
+    accessed: crate::FileTime,
+    created: crate::FileTime,
+}
+
+impl NtfsTimes {
+    /// The ID in the header of the extra field.
+    pub const HEADER_ID: u16 = 0x000a;
+
+    /// The tag of the attribute holding the times.
+    const TIMES_TAG: u16 = 0x0001;
+
+    /// Gets the times from the modification, access and creation times.
+    pub const fn new(
+        modified: crate::FileTime, accessed: crate::FileTime, created: crate::FileTime,
+    ) -> Self {
+        Self { modified, accessed, created, }
+    }
+
+    /// Gets the modification time.
+    pub const fn modified(&self) -> crate::FileTime {
+        self.modified
+    }
+
+    /// Gets the access time.
+    pub const fn accessed(&self) -> crate::FileTime {
+        self.accessed
+    }
+
+    /// Gets the creation time.
+    pub const fn created(&self) -> crate::FileTime {
+        self.created
+    }
+
+    /// Decodes the data of the extra field, after its header, or `None` if
+    /// it is truncated or has no times attribute.
+    pub fn from_data(data: &[u8]) -> Option<Self> {
+        // Four reserved bytes, then attributes of a tag, size and value.
+        let mut attributes = data.get(4..)?;
+        while attributes.len() >= 4 {
+            let tag = u16::from_le_bytes([attributes[0], attributes[1]]);
+            let size = u16::from_le_bytes([attributes[2], attributes[3]]) as usize;
+            let value = attributes.get(4..4 + size)?;
+            if tag == Self::TIMES_TAG && size >= 24 {
+                let filetime = |at: usize| {
+                    let mut bytes = [0; 8];
+                    bytes.copy_from_slice(&value[at..at + 8]);
+                    crate::FileTime::from_bytes(bytes)
+                };
+                return Some(Self::new(filetime(0), filetime(8), filetime(16)));
+            }
+
+            attributes = &attributes[4 + size..];
+        }
+        None
+    }
+
+    /// Encodes the extra field, with its header.
+    pub const fn to_field(&self) -> [u8; 36] {
+        let mut field = [0; 36];
+        let header = Self::HEADER_ID.to_le_bytes();
+        let (tag, size) = (Self::TIMES_TAG.to_le_bytes(), 24_u16.to_le_bytes());
+        let prefix = [header[0], header[1], 32, 0, 0, 0, 0, 0, tag[0], tag[1], size[0], size[1]];
+        let times = [self.modified.to_bytes(), self.accessed.to_bytes(), self.created.to_bytes()];
+        let mut i = 0;
+        while i < prefix.len() {
+            field[i] = prefix[i];
+            i += 1;
+        }
+        let mut i = 0;
+        while i < 24 {
+            field[12 + i] = times[i / 8][i % 8];
+            i += 1;
+        }
+        field
+    }
+}